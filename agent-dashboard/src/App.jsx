@@ -1,5 +1,6 @@
 import WorkspaceLayout from "@/app/WorkspaceLayout";
 import AuthView from "@/features/auth/AuthView";
+import CampaignsView from "@/features/campaigns/CampaignsView";
 import ContactsView from "@/features/contacts/ContactsView";
 import ConversationsView from "@/features/conversations/ConversationsView";
 import CsatView from "@/features/csat/CsatView";
@@ -15,6 +16,11 @@ const API_URL = import.meta.env.VITE_API_URL ?? "http://localhost:4000";
 const WS_URL = import.meta.env.VITE_WS_URL ?? "ws://localhost:4000/ws";
 const TOKEN_KEY = "agent_auth_token";
 const API_BASE = API_URL.replace(/\/+$/, "");
+const PRIORITY_RANK = { urgent: 0, high: 1, normal: 2, low: 3 };
+
+function priorityRank(session) {
+  return PRIORITY_RANK[String(session.priority || "normal").toLowerCase()] ?? 2;
+}
 
 function resolveApiUrl(url) {
   const value = String(url || "").trim();
@@ -127,6 +133,45 @@ const FLOW_NODE_PRESETS = {
     duration: 60,
     unit: "seconds",
   },
+  callback: {
+    label: "Callback",
+    text: "When should we call you back?",
+    days: 7,
+    askPhone: true,
+    submitLabel: "Book callback",
+    unavailableText: "",
+  },
+  escalate_ticket: {
+    label: "Escalate Ticket",
+    provider: "zendesk",
+    subject: "",
+    note: "",
+    priority: "",
+  },
+  action: {
+    label: "Action",
+    accountId: "",
+    accountName: "",
+    params: {},
+  },
+  book_meeting: {
+    label: "Book Meeting",
+    text: "Pick a time that works for you.",
+    title: "Meeting",
+    durationMinutes: 30,
+    days: 7,
+    askName: true,
+    submitLabel: "Book meeting",
+    unavailableText: "",
+    hours: [],
+  },
+  business_hours: {
+    label: "Business Hours",
+    timezone: "",
+    windows: [
+      { days: ["mon", "tue", "wed", "thu", "fri"], start: "09:00", end: "17:00" },
+    ],
+  },
   assign: {
     label: "Assign",
     assignTo: "team",
@@ -309,8 +354,14 @@ export default function App() {
   const [channelScope, setChannelScope] = useState("all");
   const [tagScope, setTagScope] = useState("all");
   const [visitorDraftBySession, setVisitorDraftBySession] = useState({});
+  const [agentTypersBySession, setAgentTypersBySession] = useState({});
+  const [sessionViewersBySession, setSessionViewersBySession] = useState({});
+  const [whispers, setWhispers] = useState([]);
+  const [monitoringSessionId, setMonitoringSessionId] = useState("");
+  const [sessionMonitors, setSessionMonitors] = useState({});
   const [cannedReplies, setCannedReplies] = useState([]);
   const [cannedPanelOpen, setCannedPanelOpen] = useState(false);
+  const [replyTemplates, setReplyTemplates] = useState([]);
   const [messageAudience, setMessageAudience] = useState("user");
   const [newCanned, setNewCanned] = useState({
     title: "",
@@ -332,6 +383,7 @@ export default function App() {
   const [csatReport, setCsatReport] = useState({
     count: 0,
     average: 0,
+    byAgent: [],
     surveys: [],
   });
   const [newContact, setNewContact] = useState({
@@ -356,6 +408,7 @@ export default function App() {
     useState({});
 
   const [flows, setFlows] = useState([]);
+  const [flowTemplates, setFlowTemplates] = useState([]);
   const [activeFlowId, setActiveFlowId] = useState("");
   const [flowName, setFlowName] = useState("Untitled flow");
   const [flowDescription, setFlowDescription] = useState("");
@@ -368,11 +421,14 @@ export default function App() {
   const [flowInputVariables, setFlowInputVariables] = useState([]);
   const [flowAiTool, setFlowAiTool] = useState(false);
   const [flowAiToolDescription, setFlowAiToolDescription] = useState("");
+  const [flowMaxSteps, setFlowMaxSteps] = useState(0);
 
   const wsRef = useRef(null);
   const reconnectTimerRef = useRef(null);
   const typingIdleTimerRef = useRef(null);
   const typingActiveRef = useRef(false);
+  const draftTimerRef = useRef(null);
+  const draftSyncedRef = useRef("");
   const activeIdRef = useRef("");
   const bottomRef = useRef(null);
 
@@ -408,18 +464,29 @@ export default function App() {
     };
     const draft = visitorDraftBySession[session.id];
     if (draft) return trimPreview(`Typing: ${draft}`);
-    return trimPreview(session.lastMessage?.text) || "No messages yet";
+    return (
+      trimPreview(session.title) ||
+      trimPreview(session.lastMessage?.text) ||
+      "No messages yet"
+    );
   };
 
   const sessionsByStatus = useMemo(() => {
-    return sessions.filter((session) => {
-      const status = String(session.status || "open").toLowerCase();
-      if (conversationFilter === "active") {
-        return status === "open" || status === "awaiting";
-      }
-      if (conversationFilter === "all") return true;
-      return status === conversationFilter;
-    });
+    return sessions
+      .filter((session) => {
+        const status = String(session.status || "open").toLowerCase();
+        if (conversationFilter === "active") {
+          return status === "open" || status === "awaiting";
+        }
+        if (conversationFilter === "all") return true;
+        return status === conversationFilter;
+      })
+      .sort(
+        (a, b) =>
+          Number(Boolean(b.pinned)) - Number(Boolean(a.pinned)) ||
+          priorityRank(a) - priorityRank(b) ||
+          String(b.updatedAt || "").localeCompare(String(a.updatedAt || "")),
+      );
   }, [sessions, conversationFilter]);
 
   const unreadMentionSessionIds = useMemo(() => {
@@ -488,7 +555,10 @@ export default function App() {
           : (session.lastMessage?.text ?? "No messages yet")
       ).toLowerCase();
       const id = session.id.toLowerCase();
-      return id.includes(query) || preview.includes(query);
+      const title = String(session.title ?? "").toLowerCase();
+      return (
+        id.includes(query) || title.includes(query) || preview.includes(query)
+      );
     });
   }, [
     sessionsByInboxScope,
@@ -687,6 +757,7 @@ export default function App() {
       );
       setFlowAiTool(Boolean(flow.aiTool));
       setFlowAiToolDescription(flow.aiToolDescription ?? "");
+      setFlowMaxSteps(Number(flow.maxSteps) || 0);
       setSelectedNodeId("");
     },
     [setFlowEdges, setFlowNodes],
@@ -698,6 +769,26 @@ export default function App() {
     ws.send(JSON.stringify({ event, data }));
   };
 
+  // Monitoring is silent: the visitor sees nothing until a barge-in.
+  const toggleMonitoring = () => {
+    if (!activeId) return;
+    const active = monitoringSessionId !== activeId;
+    sendWsEvent("supervisor:monitor", { sessionId: activeId, active });
+    setMonitoringSessionId(active ? activeId : "");
+  };
+
+  const sendWhisper = (value) => {
+    const trimmed = value.trim();
+    if (!activeId || !trimmed) return;
+    sendWsEvent("supervisor:whisper", { sessionId: activeId, text: trimmed });
+  };
+
+  const bargeIn = (takeover) => {
+    if (!activeId) return;
+    sendWsEvent("supervisor:barge-in", { sessionId: activeId, takeover });
+    setMonitoringSessionId("");
+  };
+
   const sendTypingState = (active, sessionOverride) => {
     const sessionId = sessionOverride ?? activeIdRef.current;
     if (!sessionId) return;
@@ -706,6 +797,14 @@ export default function App() {
     sendWsEvent("agent:typing", { sessionId, active });
   };
 
+  const flushDraft = (sessionId, value) => {
+    if (draftTimerRef.current) clearTimeout(draftTimerRef.current);
+    draftTimerRef.current = null;
+    if (!sessionId || draftSyncedRef.current === value) return;
+    draftSyncedRef.current = value;
+    sendWsEvent("agent:draft", { sessionId, text: value });
+  };
+
   const bumpTyping = () => {
     if (!activeIdRef.current) return;
     sendTypingState(true);
@@ -738,7 +837,9 @@ export default function App() {
       channelsRes,
       agentsRes,
       flowsRes,
+      flowTemplatesRes,
       cannedRes,
+      replyTemplatesRes,
       tenantsRes,
       settingsRes,
       contactsRes,
@@ -753,7 +854,9 @@ export default function App() {
       apiFetch("/api/channels", authToken),
       apiFetch("/api/agents", authToken),
       apiFetch("/api/flows", authToken),
+      apiFetch("/api/flow-templates", authToken),
       apiFetch("/api/canned-replies", authToken),
+      apiFetch("/api/reply-templates", authToken),
       apiFetch("/api/tenants", authToken),
       apiFetch("/api/tenant/settings", authToken),
       apiFetch("/api/contacts", authToken),
@@ -770,12 +873,14 @@ export default function App() {
     setChannelRecords(channelsRes.channelRecords ?? []);
     setAgents(agentsRes.agents ?? []);
     setCannedReplies(cannedRes.cannedReplies ?? []);
+    setReplyTemplates(replyTemplatesRes.replyTemplates ?? []);
     setTenants(tenantsRes.tenants ?? []);
     setTenantSettings(settingsRes.settings ?? null);
     setContacts(contactsRes.contacts ?? []);
     setCsatReport({
       count: csatRes.count ?? 0,
       average: csatRes.average ?? 0,
+      byAgent: csatRes.byAgent ?? [],
       surveys: csatRes.surveys ?? [],
     });
     setTags(tagsRes.tags ?? []);
@@ -783,6 +888,7 @@ export default function App() {
     setNotifications(notificationsRes.notifications ?? []);
     setNotificationsUnreadCount(notificationsRes.unreadCount ?? 0);
 
+    setFlowTemplates(flowTemplatesRes.templates ?? []);
     const nextFlows = flowsRes.flows ?? [];
     setFlows(nextFlows);
     if (nextFlows[0]) {
@@ -846,15 +952,82 @@ export default function App() {
         if (envelope?.event === "session:updated") {
           const session = envelope.data;
           setSessions((prev) => {
+            const previous = prev.find((s) => s.id === session.id);
             const next = prev.filter((s) => s.id !== session.id);
+            if (session.archivedAt || session.deletedAt) return next;
+            // Keep the aging badge while the same wait goes on.
+            if (
+              session.waitingSince &&
+              previous?.waitingSince === session.waitingSince
+            ) {
+              session.waitingThresholdMins = previous.waitingThresholdMins;
+            }
             return [session, ...next];
           });
         }
 
+        // Aging is computed on the server clock; just mark the threshold.
+        if (envelope?.event === "session:waiting") {
+          const payload = envelope.data ?? {};
+          setSessions((prev) =>
+            prev.map((s) =>
+              s.id === payload.sessionId
+                ? {
+                    ...s,
+                    waitingSince: payload.waitingSince,
+                    waitingThresholdMins: payload.thresholdMins,
+                  }
+                : s,
+            ),
+          );
+        }
+
         if (envelope?.event === "session:history") {
           setMessages(Array.isArray(envelope.data) ? envelope.data : []);
         }
 
+        if (envelope?.event === "session:draft") {
+          const payload = envelope.data ?? {};
+          const draft = String(payload.text ?? "");
+          if (payload.sessionId !== activeIdRef.current || !draft) return;
+          // Never overwrite what was typed here before the draft arrived.
+          setText((prev) => (prev.trim() ? prev : draft));
+          draftSyncedRef.current = draft;
+        }
+
+        if (envelope?.event === "session:whispers") {
+          const payload = envelope.data ?? {};
+          if (payload.sessionId !== activeIdRef.current) return;
+          setWhispers(Array.isArray(payload.whispers) ? payload.whispers : []);
+        }
+
+        if (envelope?.event === "session:attributes") {
+          const payload = envelope.data ?? {};
+          if (payload.sessionId !== activeIdRef.current) return;
+          setConversationAttrs(
+            Array.isArray(payload.attributes) ? payload.attributes : [],
+          );
+        }
+
+        if (envelope?.event === "supervisor:whisper") {
+          const whisper = envelope.data;
+          if (!whisper || whisper.sessionId !== activeIdRef.current) return;
+          setWhispers((prev) =>
+            prev.some((w) => w.id === whisper.id) ? prev : [...prev, whisper],
+          );
+        }
+
+        if (envelope?.event === "supervisor:monitoring") {
+          const payload = envelope.data ?? {};
+          if (!payload.sessionId) return;
+          setSessionMonitors((prev) => ({
+            ...prev,
+            [payload.sessionId]: Array.isArray(payload.supervisors)
+              ? payload.supervisors
+              : [],
+          }));
+        }
+
         if (envelope?.event === "message:new") {
           const message = envelope.data;
           if (!message || message.sessionId !== activeIdRef.current) return;
@@ -898,6 +1071,50 @@ export default function App() {
           });
         }
 
+        if (envelope?.event === "typing") {
+          const payload = envelope.data ?? {};
+          const sessionId = payload.sessionId;
+          if (!sessionId || payload.sender !== "agent") return;
+          const typers = Array.isArray(payload.agents) ? payload.agents : [];
+          setAgentTypersBySession((prev) => {
+            const next = { ...prev };
+            if (typers.length === 0) {
+              delete next[sessionId];
+            } else {
+              next[sessionId] = typers;
+            }
+            return next;
+          });
+        }
+
+        if (envelope?.event === "session:viewers") {
+          const payload = envelope.data ?? {};
+          if (!payload.sessionId) return;
+          const viewers = Array.isArray(payload.viewers) ? payload.viewers : [];
+          setSessionViewersBySession((prev) => ({
+            ...prev,
+            [payload.sessionId]: viewers,
+          }));
+        }
+
+        // A teammate answered the visitor moments ago: confirm before sending
+        // ours, or put the text back in the composer.
+        if (envelope?.event === "agent:send-blocked") {
+          const payload = envelope.data ?? {};
+          if (payload.reason !== "reply_collision") return;
+          const blockedText = String(payload.text || "");
+          const who = payload.agentName || "Another agent";
+          if (confirm(`${who} just replied to this visitor. Send yours anyway?`)) {
+            sendWsEvent("agent:message", {
+              sessionId: payload.sessionId,
+              text: blockedText,
+              force: true,
+            });
+          } else if (payload.sessionId === activeIdRef.current) {
+            setText((prev) => (prev.trim() ? prev : blockedText));
+          }
+        }
+
         if (envelope?.event === "notification:new") {
           const payload = envelope.data ?? {};
           const notification = payload.notification;
@@ -1091,12 +1308,31 @@ export default function App() {
     return () => cleanupSocket();
   }, [token]);
 
+  // Drafts are saved after a pause so another device can pick them up.
+  useEffect(() => {
+    const sessionId = activeIdRef.current;
+    if (!sessionId || draftSyncedRef.current === text) return;
+    if (draftTimerRef.current) clearTimeout(draftTimerRef.current);
+    draftTimerRef.current = setTimeout(() => flushDraft(sessionId, text), 800);
+  }, [text]);
+
   useEffect(() => {
     const previous = activeIdRef.current;
     if (previous && previous !== activeId) {
       sendTypingState(false, previous);
       typingActiveRef.current = false;
       if (typingIdleTimerRef.current) clearTimeout(typingIdleTimerRef.current);
+      flushDraft(previous, text);
+      draftSyncedRef.current = "";
+      setText("");
+      setWhispers([]);
+      if (monitoringSessionId) {
+        sendWsEvent("supervisor:monitor", {
+          sessionId: monitoringSessionId,
+          active: false,
+        });
+        setMonitoringSessionId("");
+      }
     }
 
     activeIdRef.current = activeId;
@@ -1288,6 +1524,7 @@ export default function App() {
     setNotes([]);
     setFlows([]);
     setCannedReplies([]);
+    setReplyTemplates([]);
     setTags([]);
     setSessionTags([]);
     setSessionContact(null);
@@ -1315,6 +1552,8 @@ export default function App() {
       text: text.trim(),
       internal: messageAudience === "team",
     });
+    if (draftTimerRef.current) clearTimeout(draftTimerRef.current);
+    draftSyncedRef.current = "";
     setText("");
     setMessageAudience("user");
     setCannedPanelOpen(false);
@@ -1419,6 +1658,61 @@ export default function App() {
     [token],
   );
 
+  const getVisitorBan = useCallback(
+    async (sessionId) => {
+      if (!token || !sessionId) return null;
+      const payload = await apiFetch(`/api/session/${sessionId}/ban`, token);
+      return payload?.ban ?? null;
+    },
+    [token],
+  );
+
+  const banVisitor = useCallback(
+    async (sessionId, reason) => {
+      if (!token || !sessionId) return [];
+      const payload = await apiFetch(`/api/session/${sessionId}/ban`, token, {
+        method: "POST",
+        body: JSON.stringify({ reason: reason || "" }),
+      });
+      return payload?.bans ?? [];
+    },
+    [token],
+  );
+
+  const liftVisitorBan = useCallback(
+    async (sessionId) => {
+      if (!token || !sessionId) return;
+      await apiFetch(`/api/session/${sessionId}/ban`, token, { method: "DELETE" });
+    },
+    [token],
+  );
+
+  const listSessionTickets = useCallback(
+    async (sessionId) => {
+      if (!token || !sessionId) return [];
+      const payload = await apiFetch(`/api/session/${sessionId}/tickets`, token);
+      return payload?.tickets ?? [];
+    },
+    [token],
+  );
+
+  const listTicketIntegrations = useCallback(async () => {
+    if (!token) return [];
+    const payload = await apiFetch("/api/tenant/ticket-integrations", token);
+    return (payload?.integrations ?? []).filter((item) => item.enabled);
+  }, [token]);
+
+  const escalateSession = useCallback(
+    async (sessionId, body) => {
+      const payload = await apiFetch(`/api/session/${sessionId}/tickets`, token, {
+        method: "POST",
+        body: JSON.stringify(body),
+      });
+      return payload?.ticket;
+    },
+    [token],
+  );
+
   const saveNote = async () => {
     if (!token || !activeId || !noteText.trim()) return;
     const payload = await apiFetch(`/api/session/${activeId}/notes`, token, {
@@ -1489,6 +1783,7 @@ export default function App() {
     if (!token || !tenantSettings) return;
     const payload = await apiFetch("/api/tenant/settings", token, {
       method: "PATCH",
+      headers: { "If-Match": `"${tenantSettings.version ?? 1}"` },
       body: JSON.stringify(tenantSettings),
     });
     setTenantSettings(payload.settings ?? null);
@@ -1601,6 +1896,31 @@ export default function App() {
     await patchActiveSession("meta", patch);
   };
 
+  const archiveActiveSession = async () => {
+    if (!token || !activeId) return;
+    const sessionId = activeId;
+    await apiFetch(`/api/session/${sessionId}/archive`, token, {
+      method: "POST",
+    });
+    setSessions((prev) => prev.filter((s) => s.id !== sessionId));
+    setActiveId("");
+  };
+
+  // Pins and stars are per agent; the server echoes the session with ours.
+  const markActiveSession = async (mark) => {
+    if (!token || !activeId) return;
+    const payload = await apiFetch(`/api/session/${activeId}/pin`, token, {
+      method: "PUT",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(mark),
+    });
+    if (payload?.session) {
+      setSessions((prev) =>
+        prev.map((s) => (s.id === payload.session.id ? payload.session : s)),
+      );
+    }
+  };
+
   const resolveTemplate = (body) => {
     if (!body) return "";
     return body
@@ -1616,6 +1936,28 @@ export default function App() {
     setCannedPanelOpen(false);
   };
 
+  // Templates render server-side so contact, agent and conversation
+  // variables resolve against the active session.
+  const insertReplyTemplate = async (template) => {
+    if (!token || !activeId || !template?.id) return;
+    const payload = await apiFetch(
+      `/api/session/${activeId}/reply-templates/${template.id}/render`,
+      token,
+      { method: "POST" },
+    );
+    const rendered = payload.text || "";
+    if (!rendered.trim()) return;
+    setText((prev) => (prev.trim() ? `${prev}\n${rendered}` : rendered));
+    setCannedPanelOpen(false);
+    setReplyTemplates((prev) =>
+      prev.map((item) =>
+        item.id === template.id
+          ? { ...item, usageCount: (item.usageCount ?? 0) + 1 }
+          : item,
+      ),
+    );
+  };
+
   const createCannedReply = async (e) => {
     e.preventDefault();
     if (!token || !newCanned.title.trim() || !newCanned.body.trim()) return;
@@ -1672,6 +2014,56 @@ export default function App() {
     setView("flows");
   };
 
+  const createFlowFromTemplate = async (templateKey) => {
+    if (!token || !templateKey) return;
+    const payload = await apiFetch(
+      `/api/flow-templates/${templateKey}/instantiate`,
+      token,
+      { method: "POST", body: JSON.stringify({ values: {} }) },
+    );
+    const created = payload.flow;
+    if (!created) return;
+    setFlows((prev) => [...prev, created]);
+    setActiveFlowId(created.id);
+    loadFlowIntoEditor(created);
+    setView("flows");
+  };
+
+  const exportFlow = async () => {
+    if (!token || !activeFlowId) return;
+    const bundle = await apiFetch(`/api/flows/${activeFlowId}/export`, token);
+    const blob = new Blob([JSON.stringify(bundle, null, 2)], {
+      type: "application/json",
+    });
+    const url = URL.createObjectURL(blob);
+    const link = document.createElement("a");
+    link.href = url;
+    link.download = `${(bundle?.flow?.name || "flow").replace(/[^a-z0-9]+/gi, "-").toLowerCase()}.flow.json`;
+    link.click();
+    URL.revokeObjectURL(url);
+  };
+
+  const importFlow = async (file) => {
+    if (!token || !file) return;
+    const bundle = JSON.parse(await file.text());
+    const payload = await apiFetch("/api/flows/import", token, {
+      method: "POST",
+      body: JSON.stringify(bundle),
+    });
+    const created = Array.isArray(payload.flows) ? payload.flows : [];
+    if (created.length === 0) return;
+    setFlows((prev) => [...prev, ...created]);
+    if (Array.isArray(payload.cannedReplies) && payload.cannedReplies.length) {
+      setCannedReplies((prev) =>
+        [...prev, ...payload.cannedReplies].sort((a, b) =>
+          a.title.localeCompare(b.title),
+        ),
+      );
+    }
+    setActiveFlowId(created[0].id);
+    loadFlowIntoEditor(created[0]);
+  };
+
   const saveFlow = async () => {
     if (!token || !activeFlowId) return;
     setFlowSaveStatus("Saving...");
@@ -1684,6 +2076,7 @@ export default function App() {
         .filter((edge) => edge.source && edge.target);
       const payload = await apiFetch(`/api/flows/${activeFlowId}`, token, {
         method: "PATCH",
+        headers: { "If-Match": `"${activeFlow?.version ?? 1}"` },
         body: JSON.stringify({
           name: flowName,
           description: flowDescription,
@@ -1693,6 +2086,7 @@ export default function App() {
           inputVariables: flowInputVariables,
           aiTool: flowAiTool,
           aiToolDescription: flowAiToolDescription,
+          maxSteps: flowMaxSteps,
         }),
       });
 
@@ -1819,6 +2213,23 @@ export default function App() {
       );
     }
 
+    if (widget.type === "kb_citations") {
+      const articles = Array.isArray(widget.articles) ? widget.articles : [];
+      if (articles.length === 0) return null;
+      return (
+        <div className="agent-widget agent-link-preview">
+          <div className="agent-link-body">
+            <p className="agent-link-site">Sources</p>
+            {articles.map((article) => (
+              <p key={article?.id || article?.slug}>
+                {article?.title || "Article"}
+              </p>
+            ))}
+          </div>
+        </div>
+      );
+    }
+
     if (widget.type === "attachment") {
       const attachmentType = String(
         widget.attachmentType || widget.kind || "file",
@@ -1846,6 +2257,8 @@ export default function App() {
                     ? "Location"
                     : "Attachment");
       const href = resolveApiUrl(widget.url || widget.mapUrl || "");
+      const thumbnailSrc = resolveApiUrl(widget.thumbnailUrl || "") || href;
+      const previewHref = resolveApiUrl(widget.previewUrl || "") || href;
       const caption =
         widget.caption || widget.description || (href ? "" : message?.text || "");
 
@@ -1854,10 +2267,12 @@ export default function App() {
           className={`agent-widget agent-attachment ${isAgentTone ? "agent-attachment-agent" : "agent-attachment-neutral"}`}
         >
           {canPreviewImage && href ? (
-            <a href={href} target="_blank" rel="noreferrer noopener">
+            <a href={previewHref} target="_blank" rel="noreferrer noopener">
               <img
-                src={href}
+                src={thumbnailSrc}
                 alt={title}
+                width={widget.width || undefined}
+                height={widget.height || undefined}
                 className="agent-attachment-image"
                 loading="lazy"
               />
@@ -2065,6 +2480,14 @@ export default function App() {
           activeSession={activeSession}
           messages={messages}
           visitorDraftBySession={visitorDraftBySession}
+          agentTypersBySession={agentTypersBySession}
+          sessionViewersBySession={sessionViewersBySession}
+          whispers={whispers}
+          monitoringSessionId={monitoringSessionId}
+          sessionMonitors={sessionMonitors}
+          toggleMonitoring={toggleMonitoring}
+          sendWhisper={sendWhisper}
+          bargeIn={bargeIn}
           bottomRef={bottomRef}
           sendMessage={sendMessage}
           sendAttachment={sendAttachment}
@@ -2078,16 +2501,26 @@ export default function App() {
           getWhatsappBlockStatus={getWhatsappBlockStatus}
           blockWhatsappContact={blockWhatsappContact}
           unblockWhatsappContact={unblockWhatsappContact}
+          getVisitorBan={getVisitorBan}
+          banVisitor={banVisitor}
+          liftVisitorBan={liftVisitorBan}
+          listSessionTickets={listSessionTickets}
+          listTicketIntegrations={listTicketIntegrations}
+          escalateSession={escalateSession}
           messageAudience={messageAudience}
           setMessageAudience={setMessageAudience}
           cannedPanelOpen={cannedPanelOpen}
           setCannedPanelOpen={setCannedPanelOpen}
           patchSessionMeta={patchSessionMeta}
+          archiveActiveSession={archiveActiveSession}
+          markActiveSession={markActiveSession}
           isActiveSessionClosed={isActiveSessionClosed}
           slashQuery={slashQuery}
           filteredCannedReplies={filteredCannedReplies}
           insertCannedReply={insertCannedReply}
           deleteCannedReply={deleteCannedReply}
+          replyTemplates={replyTemplates}
+          insertReplyTemplate={insertReplyTemplate}
           text={text}
           setText={setText}
           bumpTyping={bumpTyping}
@@ -2147,6 +2580,8 @@ export default function App() {
           setChannelRecords={setChannelRecords}
           cannedReplies={cannedReplies}
           setCannedReplies={setCannedReplies}
+          replyTemplates={replyTemplates}
+          setReplyTemplates={setReplyTemplates}
           tags={tags}
           setTags={setTags}
           apiFetch={apiFetch}
@@ -2171,6 +2606,10 @@ export default function App() {
         <FlowsView
           flows={flows}
           createFlow={createFlow}
+          flowTemplates={flowTemplates}
+          createFlowFromTemplate={createFlowFromTemplate}
+          exportFlow={exportFlow}
+          importFlow={importFlow}
           activeFlowId={activeFlowId}
           setActiveFlowId={setActiveFlowId}
           loadFlowIntoEditor={loadFlowIntoEditor}
@@ -2204,10 +2643,14 @@ export default function App() {
           setFlowAiTool={setFlowAiTool}
           flowAiToolDescription={flowAiToolDescription}
           setFlowAiToolDescription={setFlowAiToolDescription}
+          flowMaxSteps={flowMaxSteps}
+          setFlowMaxSteps={setFlowMaxSteps}
         />
       </section>
     ) : view === "knowledge" ? (
       <KnowledgeBaseView apiFetch={apiFetch} token={token} />
+    ) : view === "campaigns" ? (
+      <CampaignsView apiFetch={apiFetch} token={token} />
     ) : view === "contacts" ? (
       <section className="crm-main h-full min-h-0 bg-[#f8f9fb]">
         <ContactsView
@@ -2225,7 +2668,7 @@ export default function App() {
       </section>
     ) : view === "csat" ? (
       <section className="crm-main h-full min-h-0 bg-[#f8f9fb]">
-        <CsatView csatReport={csatReport} />
+        <CsatView csatReport={csatReport} agents={agents} />
       </section>
     ) : null;
 
@@ -2283,6 +2726,8 @@ export default function App() {
         setChannelRecords={setChannelRecords}
         cannedReplies={cannedReplies}
         setCannedReplies={setCannedReplies}
+        replyTemplates={replyTemplates}
+        setReplyTemplates={setReplyTemplates}
         tags={tags}
         setTags={setTags}
         apiFetch={apiFetch}
@@ -14,10 +14,13 @@ import {
   ChevronRight,
   CircleUserRound,
   Inbox,
+  Megaphone,
   MessageSquare,
+  Pin,
   Search,
   Settings,
   Smile,
+  Star,
   UserMinus,
   UserRound,
   Workflow,
@@ -30,6 +33,7 @@ const NAV_ITEMS = [
   { id: "inbox", icon: Inbox, title: "Inbox" },
   { id: "flows", icon: Workflow, title: "Flow Builder" },
   { id: "knowledge", icon: BookOpenText, title: "Knowledge Base" },
+  { id: "campaigns", icon: Megaphone, title: "Campaigns" },
   { id: "contacts", icon: UserRound, title: "Contacts" },
   { id: "settings", icon: Settings, title: "Settings" },
   { id: "csat", icon: Smile, title: "CSAT" },
@@ -681,7 +685,20 @@ export default function WorkspaceLayout({
                         <p className="min-w-0 truncate text-sm font-medium text-slate-900">
                           {getSessionTitle(session)}
                         </p>
-                        <span className="shrink-0 text-[10px] text-slate-400">
+                        <span className="flex shrink-0 items-center gap-1 text-[10px] text-slate-400">
+                          {session.waitingSince &&
+                          session.waitingThresholdMins ? (
+                            <span
+                              className="rounded-full bg-rose-50 px-1.5 py-0.5 font-semibold text-rose-600"
+                              title="Waiting for a reply"
+                            >
+                              {session.waitingThresholdMins}m+
+                            </span>
+                          ) : null}
+                          {session.pinned ? <Pin size={10} /> : null}
+                          {session.starred ? (
+                            <Star size={10} className="text-amber-500" />
+                          ) : null}
                           {formatTime(session.updatedAt)}
                         </span>
                       </div>
@@ -801,7 +818,23 @@ export default function WorkspaceLayout({
                                   <p className="min-w-0 truncate text-sm font-medium text-slate-900">
                                     {getSessionTitle(session)}
                                   </p>
-                                  <span className="shrink-0 text-[10px] text-slate-400">
+                                  <span className="flex shrink-0 items-center gap-1 text-[10px] text-slate-400">
+                                    {session.waitingSince &&
+                                    session.waitingThresholdMins ? (
+                                      <span
+                                        className="rounded-full bg-rose-50 px-1.5 py-0.5 font-semibold text-rose-600"
+                                        title="Waiting for a reply"
+                                      >
+                                        {session.waitingThresholdMins}m+
+                                      </span>
+                                    ) : null}
+                                    {session.pinned ? <Pin size={10} /> : null}
+                                    {session.starred ? (
+                                      <Star
+                                        size={10}
+                                        className="text-amber-500"
+                                      />
+                                    ) : null}
                                     {formatTime(session.updatedAt)}
                                   </span>
                                 </div>
@@ -830,6 +863,11 @@ export default function WorkspaceLayout({
                                   <p className="min-w-0 truncate text-[10px] uppercase tracking-wide text-slate-400">
                                     {titleCase(session.channel)} •{" "}
                                     {session.status || "open"}
+                                    {session.isTest ? (
+                                      <span className="ml-1 font-semibold text-amber-600">
+                                        • test
+                                      </span>
+                                    ) : null}
                                   </p>
                                   {(session.unreadCount || 0) > 0 ? (
                                     <span className="shrink-0 rounded-full bg-orange-500 px-1.5 py-0.5 text-[10px] font-semibold text-white">
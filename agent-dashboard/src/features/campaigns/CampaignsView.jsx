@@ -0,0 +1,357 @@
+import { Badge } from "@/components/ui/badge";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { ScrollArea } from "@/components/ui/scroll-area";
+import { Textarea } from "@/components/ui/textarea";
+import { Megaphone, Plus, Trash2 } from "lucide-react";
+import { useEffect, useMemo, useState } from "react";
+
+const RULE_ATTRIBUTES = [
+  ["page_url", "Page URL"],
+  ["referrer", "Referrer"],
+  ["country", "Country code"],
+  ["city", "City"],
+  ["timezone", "Timezone"],
+  ["browser", "Browser"],
+  ["os", "Operating system"],
+  ["language", "Language"],
+  ["device_type", "Device"],
+  ["visitor_type", "Visitor (new, returning)"],
+  ["visitor_attribute", "Embed attribute…"],
+];
+
+const RULE_OPERATORS = [
+  ["contains", "contains"],
+  ["equals", "equals"],
+  ["not_equals", "does not equal"],
+  ["not_contains", "does not contain"],
+  ["starts_with", "starts with"],
+  ["matches", "matches pattern"],
+];
+
+const emptyDraft = () => ({
+  name: "",
+  message: "",
+  enabled: true,
+  rules: [{ attribute: "page_url", operator: "contains", value: "" }],
+  logicOperator: "and",
+  delaySeconds: 30,
+  maxPerVisitor: 1,
+  capWindowHours: 24,
+});
+
+export default function CampaignsView({ apiFetch, token }) {
+  const [campaigns, setCampaigns] = useState([]);
+  const [stats, setStats] = useState({});
+  const [selectedId, setSelectedId] = useState("");
+  const [draft, setDraft] = useState(emptyDraft);
+  const [saving, setSaving] = useState(false);
+  const [error, setError] = useState("");
+
+  const loadCampaigns = async () => {
+    if (!token) return;
+    setError("");
+    try {
+      const res = await apiFetch("/api/campaigns", token);
+      setCampaigns(res.campaigns ?? []);
+      setStats(res.stats ?? {});
+    } catch (err) {
+      setError(err.message || "Failed to load campaigns");
+    }
+  };
+
+  useEffect(() => {
+    loadCampaigns().catch((err) => console.error("campaigns load failed", err));
+  }, [token]);
+
+  const selected = useMemo(
+    () => campaigns.find((c) => c.id === selectedId) ?? null,
+    [campaigns, selectedId],
+  );
+
+  const selectCampaign = (campaign) => {
+    setSelectedId(campaign?.id ?? "");
+    setDraft(campaign ? { ...campaign } : emptyDraft());
+  };
+
+  const updateRule = (index, patch) => {
+    setDraft((prev) => {
+      const rules = [...(prev.rules || [])];
+      rules[index] = { ...rules[index], ...patch };
+      return { ...prev, rules };
+    });
+  };
+
+  const saveCampaign = async () => {
+    if (!draft.name.trim() || !draft.message.trim()) return;
+    setSaving(true);
+    setError("");
+    const body = JSON.stringify({
+      name: draft.name,
+      message: draft.message,
+      enabled: draft.enabled,
+      rules: draft.rules,
+      logicOperator: draft.logicOperator,
+      delaySeconds: Number(draft.delaySeconds) || 0,
+      maxPerVisitor: Number(draft.maxPerVisitor) || 0,
+      capWindowHours: Number(draft.capWindowHours) || 0,
+    });
+    try {
+      const res = selected
+        ? await apiFetch(`/api/campaigns/${selected.id}`, token, {
+            method: "PATCH",
+            body,
+          })
+        : await apiFetch("/api/campaigns", token, { method: "POST", body });
+      const campaign = res.campaign;
+      if (!campaign) return;
+      setCampaigns((prev) =>
+        selected
+          ? prev.map((c) => (c.id === campaign.id ? campaign : c))
+          : [...prev, campaign],
+      );
+      selectCampaign(campaign);
+    } catch (err) {
+      setError(err.message);
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const deleteCampaign = async () => {
+    if (!selected) return;
+    setError("");
+    try {
+      await apiFetch(`/api/campaigns/${selected.id}`, token, {
+        method: "DELETE",
+      });
+      setCampaigns((prev) => prev.filter((c) => c.id !== selected.id));
+      selectCampaign(null);
+    } catch (err) {
+      setError(err.message);
+    }
+  };
+
+  return (
+    <div className="grid h-full min-h-0 grid-cols-[320px_1fr] gap-4 bg-slate-50 p-4 max-[1080px]:grid-cols-[1fr]">
+      <aside className="grid min-h-0 grid-rows-[auto_1fr] rounded-xl border border-slate-200 bg-white p-4">
+        <div className="mb-3 flex items-center justify-between">
+          <h3 className="text-sm font-semibold text-slate-900">
+            Proactive campaigns
+          </h3>
+          <Button
+            size="sm"
+            onClick={() => selectCampaign(null)}
+            className="h-7 rounded-lg bg-blue-500 px-2.5 text-[11px] text-white hover:bg-blue-600"
+          >
+            <Plus size={12} className="mr-1" /> New
+          </Button>
+        </div>
+        <ScrollArea className="h-full">
+          <div className="space-y-2 pr-2">
+            {campaigns.map((campaign) => {
+              const campaignStats = stats[campaign.id] ?? {
+                sent: 0,
+                converted: 0,
+              };
+              return (
+                <button
+                  key={campaign.id}
+                  onClick={() => selectCampaign(campaign)}
+                  className={`w-full rounded-lg border p-3 text-left ${
+                    campaign.id === selectedId
+                      ? "border-blue-200 bg-blue-50"
+                      : "border-slate-200 bg-slate-50 hover:bg-slate-100"
+                  }`}
+                >
+                  <div className="flex items-center gap-2">
+                    <Megaphone size={13} className="text-blue-500" />
+                    <p className="flex-1 truncate text-sm font-semibold text-slate-900">
+                      {campaign.name}
+                    </p>
+                    {!campaign.enabled && <Badge variant="outline">Paused</Badge>}
+                  </div>
+                  <p className="mt-1 text-[11px] text-slate-500">
+                    {campaignStats.sent} sent · {campaignStats.converted}{" "}
+                    replied
+                    {campaignStats.sent > 0 &&
+                      ` (${Math.round((campaignStats.converted / campaignStats.sent) * 100)}%)`}
+                  </p>
+                </button>
+              );
+            })}
+            {campaigns.length === 0 && (
+              <p className="text-xs text-slate-400">No campaigns yet.</p>
+            )}
+          </div>
+        </ScrollArea>
+      </aside>
+      <section className="min-h-0 overflow-auto rounded-xl border border-slate-200 bg-white p-4">
+        <h3 className="mb-3 text-sm font-semibold text-slate-900">
+          {selected ? "Edit campaign" : "New campaign"}
+        </h3>
+        {error && <p className="mb-3 text-xs text-red-600">{error}</p>}
+        <div className="max-w-xl space-y-4">
+          <Input
+            value={draft.name}
+            onChange={(e) => setDraft((prev) => ({ ...prev, name: e.target.value }))}
+            placeholder="Campaign name"
+          />
+          <Textarea
+            value={draft.message}
+            onChange={(e) =>
+              setDraft((prev) => ({ ...prev, message: e.target.value }))
+            }
+            placeholder="Hi! Have questions about our plans?"
+            rows={3}
+          />
+          <div>
+            <p className="mb-2 text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+              Show to visitors where
+            </p>
+            <div className="space-y-2">
+              {(draft.rules || []).map((rule, i) => (
+                <div key={i} className="flex items-center gap-1.5">
+                  {i > 0 && (
+                    <select
+                      className="rounded border border-slate-200 bg-white px-1.5 py-1 text-[11px] text-amber-700"
+                      value={draft.logicOperator}
+                      onChange={(e) =>
+                        setDraft((prev) => ({
+                          ...prev,
+                          logicOperator: e.target.value,
+                        }))
+                      }
+                    >
+                      <option value="and">AND</option>
+                      <option value="or">OR</option>
+                    </select>
+                  )}
+                  <select
+                    className="rounded-lg border border-slate-200 bg-white px-2 py-1.5 text-[12px]"
+                    value={rule.attribute}
+                    onChange={(e) => updateRule(i, { attribute: e.target.value })}
+                  >
+                    {RULE_ATTRIBUTES.map(([value, label]) => (
+                      <option key={value} value={value}>
+                        {label}
+                      </option>
+                    ))}
+                  </select>
+                  {rule.attribute === "visitor_attribute" && (
+                    <Input
+                      value={rule.attributeKey || ""}
+                      onChange={(e) =>
+                        updateRule(i, { attributeKey: e.target.value })
+                      }
+                      placeholder="Key"
+                      className="w-24 text-[12px]"
+                    />
+                  )}
+                  <select
+                    className="rounded-lg border border-slate-200 bg-white px-2 py-1.5 text-[12px]"
+                    value={rule.operator}
+                    onChange={(e) => updateRule(i, { operator: e.target.value })}
+                  >
+                    {RULE_OPERATORS.map(([value, label]) => (
+                      <option key={value} value={value}>
+                        {label}
+                      </option>
+                    ))}
+                  </select>
+                  <Input
+                    value={rule.value || ""}
+                    onChange={(e) => updateRule(i, { value: e.target.value })}
+                    placeholder="/pricing"
+                    className="flex-1 text-[12px]"
+                  />
+                  <button
+                    onClick={() =>
+                      setDraft((prev) => ({
+                        ...prev,
+                        rules: prev.rules.filter((_, idx) => idx !== i),
+                      }))
+                    }
+                    className="rounded p-1 text-slate-400 hover:bg-red-50 hover:text-red-500"
+                  >
+                    <Trash2 size={12} />
+                  </button>
+                </div>
+              ))}
+              <Button
+                size="sm"
+                variant="outline"
+                onClick={() =>
+                  setDraft((prev) => ({
+                    ...prev,
+                    rules: [
+                      ...(prev.rules || []),
+                      { attribute: "page_url", operator: "contains", value: "" },
+                    ],
+                  }))
+                }
+              >
+                <Plus size={12} className="mr-1" /> Add rule
+              </Button>
+            </div>
+          </div>
+          <div className="grid grid-cols-3 gap-3">
+            <label className="text-[11px] text-slate-500">
+              Seconds on page
+              <Input
+                type="number"
+                min={0}
+                value={draft.delaySeconds}
+                onChange={(e) =>
+                  setDraft((prev) => ({ ...prev, delaySeconds: e.target.value }))
+                }
+              />
+            </label>
+            <label className="text-[11px] text-slate-500">
+              Max per visitor
+              <Input
+                type="number"
+                min={0}
+                value={draft.maxPerVisitor}
+                onChange={(e) =>
+                  setDraft((prev) => ({ ...prev, maxPerVisitor: e.target.value }))
+                }
+              />
+            </label>
+            <label className="text-[11px] text-slate-500">
+              Within hours (0 = ever)
+              <Input
+                type="number"
+                min={0}
+                value={draft.capWindowHours}
+                onChange={(e) =>
+                  setDraft((prev) => ({ ...prev, capWindowHours: e.target.value }))
+                }
+              />
+            </label>
+          </div>
+          <label className="flex items-center gap-2 text-[12px] text-slate-700">
+            <input
+              type="checkbox"
+              checked={draft.enabled}
+              onChange={(e) =>
+                setDraft((prev) => ({ ...prev, enabled: e.target.checked }))
+              }
+            />
+            Enabled
+          </label>
+          <div className="flex gap-2">
+            <Button onClick={() => void saveCampaign()} disabled={saving}>
+              {saving ? "Saving..." : "Save campaign"}
+            </Button>
+            {selected && (
+              <Button variant="outline" onClick={() => void deleteCampaign()}>
+                Delete
+              </Button>
+            )}
+          </div>
+        </div>
+      </section>
+    </div>
+  );
+}
@@ -37,6 +37,8 @@ export default function ContactsView({
   const [newAttrKey, setNewAttrKey] = useState("");
   const [newAttrValue, setNewAttrValue] = useState("");
   const [detailTab, setDetailTab] = useState("details");
+  const [crmSyncing, setCrmSyncing] = useState(false);
+  const [crmSyncMessage, setCrmSyncMessage] = useState("");
 
   const selected = contacts.find((c) => c.id === selectedId) ?? null;
 
@@ -53,6 +55,7 @@ export default function ContactsView({
 
   // Load attributes & conversations when selection changes
   useEffect(() => {
+    setCrmSyncMessage("");
     if (!selectedId || !token) {
       setContactAttrs([]);
       setContactConvos([]);
@@ -92,6 +95,34 @@ export default function ContactsView({
     setContactAttrs((prev) => prev.filter((a) => a.attributeKey !== key));
   };
 
+  const syncCrm = async () => {
+    if (!selectedId) return;
+    setCrmSyncing(true);
+    setCrmSyncMessage("");
+    try {
+      const res = await apiFetch(`/api/contacts/${selectedId}/crm-sync`, token, {
+        method: "POST",
+      });
+      const results = res.results ?? [];
+      setCrmSyncMessage(
+        results.length === 0
+          ? "No CRM is connected."
+          : results
+              .map((r) => `${r.provider}: ${r.ok ? "synced" : r.detail}`)
+              .join(" · "),
+      );
+      const attrs = await apiFetch(
+        `/api/contacts/${selectedId}/attributes`,
+        token,
+      );
+      setContactAttrs(attrs.attributes ?? []);
+    } catch (err) {
+      setCrmSyncMessage(err.message);
+    } finally {
+      setCrmSyncing(false);
+    }
+  };
+
   const handleFieldBlur = async (field, value) => {
     if (!selectedId) return;
     await patchContact(selectedId, { [field]: value });
@@ -325,15 +356,54 @@ export default function ContactsView({
                         {selected.os}
                       </p>
                     )}
+                    {selected.screenSize && (
+                      <p>
+                        <span className="font-medium text-slate-700">
+                          Screen:
+                        </span>{" "}
+                        {selected.screenSize}
+                      </p>
+                    )}
+                    {selected.language && (
+                      <p>
+                        <span className="font-medium text-slate-700">
+                          Language:
+                        </span>{" "}
+                        {selected.language}
+                      </p>
+                    )}
+                    {(selected.city || selected.country) && (
+                      <p>
+                        <span className="font-medium text-slate-700">
+                          Location:
+                        </span>{" "}
+                        {[selected.city, selected.country]
+                          .filter(Boolean)
+                          .join(", ")}
+                        {selected.timezone ? ` (${selected.timezone})` : ""}
+                      </p>
+                    )}
                   </div>
                 </div>
               )}
 
               {detailTab === "attributes" && (
                 <div className="space-y-4">
-                  <p className="text-[11px] font-semibold uppercase tracking-wide text-slate-500">
-                    Custom attributes
-                  </p>
+                  <div className="flex items-center justify-between">
+                    <p className="text-[11px] font-semibold uppercase tracking-wide text-slate-500">
+                      Custom attributes
+                    </p>
+                    <button
+                      onClick={syncCrm}
+                      disabled={crmSyncing}
+                      className="text-[11px] text-blue-600 hover:text-blue-700 disabled:opacity-50"
+                    >
+                      {crmSyncing ? "Syncing…" : "Sync with CRM"}
+                    </button>
+                  </div>
+                  {crmSyncMessage && (
+                    <p className="text-xs text-slate-500">{crmSyncMessage}</p>
+                  )}
                   <div className="space-y-2">
                     {contactAttrs.map((attr) => (
                       <div
@@ -5,6 +5,7 @@ import { Input } from "@/components/ui/input";
 import { ScrollArea } from "@/components/ui/scroll-area";
 import { Textarea } from "@/components/ui/textarea";
 import {
+  Archive,
   ArrowLeft,
   AtSign,
   Building2,
@@ -15,17 +16,21 @@ import {
   CircleDashed,
   ClipboardList,
   Clock3,
+  ExternalLink,
   Mail,
   MapPin,
   MessageCircle,
+  Monitor,
   MoreVertical,
   Paperclip,
   PhoneCall,
   PhoneOff,
   Phone,
+  Pin,
   Plus,
   Send,
   Smile,
+  Star,
   Tag,
   X,
 } from "lucide-react";
@@ -313,6 +318,14 @@ export default function ConversationsView({
   activeSession,
   messages,
   visitorDraftBySession,
+  agentTypersBySession,
+  sessionViewersBySession = {},
+  whispers = [],
+  monitoringSessionId = "",
+  sessionMonitors = {},
+  toggleMonitoring,
+  sendWhisper,
+  bargeIn,
   bottomRef,
   sendMessage,
   sendAttachment,
@@ -321,11 +334,15 @@ export default function ConversationsView({
   cannedPanelOpen,
   setCannedPanelOpen,
   patchSessionMeta,
+  archiveActiveSession,
+  markActiveSession,
   isActiveSessionClosed,
   slashQuery,
   filteredCannedReplies,
   insertCannedReply,
   deleteCannedReply,
+  replyTemplates = [],
+  insertReplyTemplate,
   text,
   setText,
   bumpTyping,
@@ -363,6 +380,12 @@ export default function ConversationsView({
   getWhatsappBlockStatus,
   blockWhatsappContact,
   unblockWhatsappContact,
+  getVisitorBan,
+  banVisitor,
+  liftVisitorBan,
+  listSessionTickets,
+  listTicketIntegrations,
+  escalateSession,
 }) {
   const [lightbox, setLightbox] = useStateReact(null);
   const [emojiOpen, setEmojiOpen] = useStateReact(false);
@@ -385,8 +408,30 @@ export default function ConversationsView({
   const [customSnoozeAt, setCustomSnoozeAt] = useStateReact("");
   const [snoozeMenuPos, setSnoozeMenuPos] = useStateReact({ top: 0, left: 0 });
   const [moreMenuOpen, setMoreMenuOpen] = useStateReact(false);
+  const [whisperText, setWhisperText] = useStateReact("");
+  const isSupervisor = agent?.role === "owner" || agent?.role === "admin";
+  const isMonitoring = Boolean(activeId) && monitoringSessionId === activeId;
+  const otherMonitors = (
+    (activeId && sessionMonitors?.[activeId]) ||
+    []
+  ).filter((supervisor) => supervisor.id !== agent?.id);
+  const otherAgentTypers = (
+    (activeId && agentTypersBySession?.[activeId]) ||
+    []
+  ).filter((typer) => typer.id !== agent?.id);
+  const otherViewers = (
+    (activeId && sessionViewersBySession?.[activeId]) ||
+    []
+  ).filter((viewer) => viewer.id !== agent?.id);
+  const filteredReplyTemplates = slashQuery
+    ? replyTemplates.filter((template) =>
+        template.title.toLowerCase().includes(slashQuery.toLowerCase()),
+      )
+    : replyTemplates;
   const [waBlocked, setWaBlocked] = useStateReact(false);
   const [waBlockLoading, setWaBlockLoading] = useStateReact(false);
+  const [visitorBan, setVisitorBan] = useStateReact(null);
+  const [banLoading, setBanLoading] = useStateReact(false);
   const [mentionOpen, setMentionOpen] = useStateReact(false);
   const [mentionQuery, setMentionQuery] = useStateReact("");
   const [mentionStart, setMentionStart] = useStateReact(-1);
@@ -396,8 +441,15 @@ export default function ConversationsView({
     contactAttrs: false,
     previousConversations: false,
     conversationNotes: false,
+    escalations: false,
     participants: false,
   });
+  const [tickets, setTickets] = useStateReact([]);
+  const [ticketProviders, setTicketProviders] = useStateReact([]);
+  const [ticketProvider, setTicketProvider] = useStateReact("");
+  const [ticketNote, setTicketNote] = useStateReact("");
+  const [ticketBusy, setTicketBusy] = useStateReact(false);
+  const [ticketError, setTicketError] = useStateReact("");
   const fileInputRef = useRef(null);
   const emojiPanelRef = useRef(null);
   const mentionPanelRef = useRef(null);
@@ -1068,6 +1120,41 @@ export default function ConversationsView({
     };
   }, [activeId, isWhatsappConversation, getWhatsappBlockStatus]);
 
+  useEffect(() => {
+    let cancelled = false;
+    setVisitorBan(null);
+    if (!activeId || !isSupervisor || !getVisitorBan) return;
+    getVisitorBan(activeId)
+      .then((ban) => {
+        if (!cancelled) setVisitorBan(ban);
+      })
+      .catch(() => {});
+    return () => {
+      cancelled = true;
+    };
+  }, [activeId, isSupervisor, getVisitorBan]);
+
+  const handleToggleVisitorBan = async () => {
+    if (!activeId || banLoading) return;
+    setBanLoading(true);
+    try {
+      if (visitorBan) {
+        await liftVisitorBan?.(activeId);
+        setVisitorBan(null);
+      } else {
+        const reason = window.prompt("Reason for the ban (optional)");
+        if (reason === null) return;
+        const bans = await banVisitor?.(activeId, reason);
+        setVisitorBan(bans?.[0] ?? null);
+      }
+      setMoreMenuOpen(false);
+    } catch (error) {
+      console.error("failed to update visitor ban", error);
+    } finally {
+      setBanLoading(false);
+    }
+  };
+
   const handleToggleWhatsappBlock = async () => {
     if (!activeId || !isWhatsappConversation || waBlockLoading) return;
     setWaBlockLoading(true);
@@ -1216,6 +1303,55 @@ export default function ConversationsView({
     waTemplateParams.length > 0 &&
     waTemplateParams.some((value) => !String(value || "").trim());
 
+  useEffect(() => {
+    if (!sidebarPanels.escalations || !activeId) return;
+    let cancelled = false;
+    setTicketError("");
+    Promise.all([
+      listSessionTickets?.(activeId) ?? [],
+      listTicketIntegrations?.() ?? [],
+    ])
+      .then(([items, integrations]) => {
+        if (cancelled) return;
+        setTickets(items);
+        setTicketProviders(integrations.map((item) => item.provider));
+        setTicketProvider((prev) =>
+          integrations.some((item) => item.provider === prev)
+            ? prev
+            : integrations[0]?.provider || "",
+        );
+      })
+      .catch((err) => {
+        if (!cancelled) setTicketError(err.message);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [
+    activeId,
+    sidebarPanels.escalations,
+    listSessionTickets,
+    listTicketIntegrations,
+  ]);
+
+  const submitEscalation = async () => {
+    if (!activeId || !ticketProvider || !escalateSession) return;
+    setTicketBusy(true);
+    setTicketError("");
+    try {
+      const ticket = await escalateSession(activeId, {
+        provider: ticketProvider,
+        note: ticketNote.trim(),
+      });
+      if (ticket) setTickets((prev) => [ticket, ...prev]);
+      setTicketNote("");
+    } catch (err) {
+      setTicketError(err.message);
+    } finally {
+      setTicketBusy(false);
+    }
+  };
+
   const toggleSidebarPanel = (key) =>
     setSidebarPanels((prev) => ({ ...prev, [key]: !prev[key] }));
 
@@ -1279,6 +1415,11 @@ export default function ConversationsView({
                         {String(activeSession.status || "open")}
                       </span>
                     ) : null}
+                    {activeSession?.isTest ? (
+                      <span className="rounded-full border border-amber-200 bg-amber-50 px-1.5 py-0.5 text-[10px] font-medium text-amber-700">
+                        Test
+                      </span>
+                    ) : null}
                   </div>
                   <p className="truncate text-[11px] text-slate-500 capitalize">
                     {String(activeSession?.channel || "conversation")}
@@ -1352,6 +1493,49 @@ export default function ConversationsView({
                           <CircleDashed size={13} className="text-slate-500" />
                           Mark as pending
                         </button>
+                        <button
+                          type="button"
+                          className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-xs text-slate-700 hover:bg-slate-50"
+                          onClick={() => {
+                            setStatusMenuOpen(false);
+                            setSnoozeMenuOpen(false);
+                            setCustomSnoozeOpen(false);
+                            void archiveActiveSession();
+                          }}
+                        >
+                          <Archive size={13} className="text-slate-500" />
+                          Archive
+                        </button>
+                        <button
+                          type="button"
+                          className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-xs text-slate-700 hover:bg-slate-50"
+                          onClick={() => {
+                            setStatusMenuOpen(false);
+                            setSnoozeMenuOpen(false);
+                            setCustomSnoozeOpen(false);
+                            void markActiveSession({
+                              pinned: !activeSession?.pinned,
+                            });
+                          }}
+                        >
+                          <Pin size={13} className="text-slate-500" />
+                          {activeSession?.pinned ? "Unpin" : "Pin to top"}
+                        </button>
+                        <button
+                          type="button"
+                          className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-xs text-slate-700 hover:bg-slate-50"
+                          onClick={() => {
+                            setStatusMenuOpen(false);
+                            setSnoozeMenuOpen(false);
+                            setCustomSnoozeOpen(false);
+                            void markActiveSession({
+                              starred: !activeSession?.starred,
+                            });
+                          }}
+                        >
+                          <Star size={13} className="text-slate-500" />
+                          {activeSession?.starred ? "Unstar" : "Star"}
+                        </button>
                       </div>
                     ) : null}
                     {snoozeMenuOpen ? (
@@ -1498,6 +1682,58 @@ export default function ConversationsView({
                                 : "Block contact"}
                           </button>
                         ) : null}
+                        {isSupervisor ? (
+                          <>
+                            <button
+                              type="button"
+                              className={`flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-xs ${
+                                visitorBan
+                                  ? "text-slate-700 hover:bg-slate-50"
+                                  : "text-red-600 hover:bg-red-50"
+                              } ${banLoading ? "cursor-not-allowed opacity-60" : ""}`}
+                              onClick={() => void handleToggleVisitorBan()}
+                              disabled={banLoading}
+                            >
+                              {banLoading
+                                ? "Updating..."
+                                : visitorBan
+                                  ? "Lift ban"
+                                  : "Ban visitor"}
+                            </button>
+                            <button
+                              type="button"
+                              className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-xs text-slate-700 hover:bg-slate-50"
+                              onClick={() => {
+                                toggleMonitoring();
+                                setMoreMenuOpen(false);
+                              }}
+                            >
+                              {isMonitoring
+                                ? "Stop monitoring"
+                                : "Monitor silently"}
+                            </button>
+                            <button
+                              type="button"
+                              className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-xs text-slate-700 hover:bg-slate-50"
+                              onClick={() => {
+                                bargeIn(false);
+                                setMoreMenuOpen(false);
+                              }}
+                            >
+                              Barge in
+                            </button>
+                            <button
+                              type="button"
+                              className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-xs text-slate-700 hover:bg-slate-50"
+                              onClick={() => {
+                                bargeIn(true);
+                                setMoreMenuOpen(false);
+                              }}
+                            >
+                              Take over
+                            </button>
+                          </>
+                        ) : null}
                         <button
                           type="button"
                           className="flex w-full cursor-not-allowed items-center gap-2 rounded-md px-2 py-1.5 text-left text-xs text-slate-400"
@@ -1512,6 +1748,38 @@ export default function ConversationsView({
                 </div>
               ) : null}
             </header>
+            {isMonitoring ? (
+              <form
+                className="flex items-center gap-2 border-b border-violet-200 bg-violet-50 px-3 py-2"
+                onSubmit={(e) => {
+                  e.preventDefault();
+                  sendWhisper(whisperText);
+                  setWhisperText("");
+                }}
+              >
+                <p className="shrink-0 text-[11px] font-medium text-violet-700">
+                  Monitoring silently
+                  {otherMonitors.length > 0
+                    ? ` with ${otherMonitors.map((s) => s.name).join(", ")}`
+                    : ""}
+                </p>
+                <Input
+                  value={whisperText}
+                  onChange={(e) => setWhisperText(e.target.value)}
+                  placeholder="Whisper to the agent…"
+                  className="h-7 flex-1 bg-white text-xs"
+                />
+                <Button
+                  type="submit"
+                  size="sm"
+                  variant="outline"
+                  className="h-7 px-2 text-xs"
+                  disabled={!whisperText.trim()}
+                >
+                  Whisper
+                </Button>
+              </form>
+            ) : null}
             <ScrollArea className="conversation-thread h-full min-h-0 px-5 py-4">
               <audio ref={remoteAudioRef} autoPlay playsInline className="hidden" />
               <div className="flex flex-col">
@@ -1661,11 +1929,18 @@ export default function ConversationsView({
                     ((isAgent || isTeam) &&
                       next &&
                       next.agentId !== message.agentId);
+                  const sameAuthorAsPrev =
+                    !prev ||
+                    (prev.agentId === message.agentId &&
+                      prev.participantId === message.participantId);
                   const gapTop =
-                    prevGroup === senderGroup &&
-                    (!prev || prev.agentId === message.agentId)
+                    prevGroup === senderGroup && sameAuthorAsPrev
                       ? "mt-1"
                       : "mt-3";
+                  const showParticipantName =
+                    isVisitor &&
+                    Boolean(message.participantName) &&
+                    (prevGroup !== senderGroup || !sameAuthorAsPrev);
                   return (
                     <article
                       key={message.id}
@@ -1700,6 +1975,11 @@ export default function ConversationsView({
                             Internal note
                           </p>
                         ) : null}
+                        {showParticipantName ? (
+                          <p className="mb-1 text-[11px] font-semibold text-slate-500">
+                            {message.participantName}
+                          </p>
+                        ) : null}
                         {attachmentWidget ? (
                           <div>
                             {(attachmentType === "image" ||
@@ -1761,6 +2041,11 @@ export default function ConversationsView({
                                   "Open attachment"}
                               </a>
                             ) : null}
+                            {attachmentWidget?.blocked ? (
+                              <p className="text-xs text-red-600">
+                                {attachmentWidget?.blockedReason}
+                              </p>
+                            ) : null}
                           </div>
                         ) : (
                           <>
@@ -1816,6 +2101,35 @@ export default function ConversationsView({
                     </time>
                   </article>
                 ) : null}
+                {whispers.map((whisper) => (
+                  <div
+                    key={whisper.id}
+                    className="my-2 rounded-lg border border-violet-200 bg-violet-50 px-3 py-2"
+                  >
+                    <p className="text-[11px] font-semibold text-violet-700">
+                      Whisper from {whisper.supervisorName || "a supervisor"}
+                    </p>
+                    <p className="whitespace-pre-wrap text-xs text-slate-700">
+                      {whisper.text}
+                    </p>
+                  </div>
+                ))}
+                {otherViewers.length > 0 ? (
+                  <p className="text-[11px] text-amber-600">
+                    {otherViewers.map((viewer) => viewer.name).join(", ")}{" "}
+                    {otherViewers.length === 1 ? "is" : "are"} also viewing
+                    {otherViewers.some((viewer) => viewer.typing)
+                      ? " and replying"
+                      : ""}
+                    .
+                  </p>
+                ) : null}
+                {otherAgentTypers.length > 0 ? (
+                  <p className="text-[11px] text-slate-400">
+                    {otherAgentTypers.map((typer) => typer.name).join(", ")}{" "}
+                    {otherAgentTypers.length === 1 ? "is" : "are"} typing...
+                  </p>
+                ) : null}
                 <div ref={bottomRef} />
               </div>
             </ScrollArea>
@@ -1868,6 +2182,38 @@ export default function ConversationsView({
                       </p>
                     ) : null}
                   </div>
+                  {filteredReplyTemplates.length > 0 ? (
+                    <div className="mt-2 border-t border-slate-100 pt-2">
+                      <p className="mb-1 text-[11px] font-semibold uppercase tracking-wide text-slate-400">
+                        Templates
+                      </p>
+                      <div className="max-h-36 space-y-1 overflow-y-auto">
+                        {filteredReplyTemplates.map((template) => (
+                          <button
+                            key={template.id}
+                            type="button"
+                            className="w-full rounded-md border border-slate-200 bg-white p-1.5 text-left hover:bg-slate-50"
+                            onClick={() => insertReplyTemplate(template)}
+                          >
+                            <p className="text-xs font-semibold text-slate-800">
+                              {template.title}
+                              {template.teamId ? (
+                                <span className="ml-1 font-normal text-slate-400">
+                                  •{" "}
+                                  {teams.find(
+                                    (team) => team.id === template.teamId,
+                                  )?.name || "Team"}
+                                </span>
+                              ) : null}
+                            </p>
+                            <p className="truncate text-[11px] text-slate-500">
+                              {template.body}
+                            </p>
+                          </button>
+                        ))}
+                      </div>
+                    </div>
+                  ) : null}
                 </div>
               )}
 
@@ -2328,6 +2674,7 @@ export default function ConversationsView({
                   ["contactAttrs", "Contact Attributes"],
                   ["previousConversations", "Previous Conversations"],
                   ["conversationNotes", "Conversation Notes"],
+                  ["escalations", "Escalations"],
                   ["participants", "Conversation Participants"],
                 ].map(([key, title]) => (
                   <section
@@ -2383,6 +2730,49 @@ export default function ConversationsView({
                                   "-"}
                               </span>
                             </div>
+                            {activeSession?.geo ? (
+                              <div className="flex items-start gap-2">
+                                <MapPin
+                                  size={12}
+                                  className="mt-0.5 text-slate-500"
+                                />
+                                <span>
+                                  {[
+                                    activeSession.geo.city,
+                                    activeSession.geo.country,
+                                  ]
+                                    .filter(Boolean)
+                                    .join(", ") || "-"}
+                                </span>
+                              </div>
+                            ) : null}
+                            {activeSession?.geo?.timezone ? (
+                              <div className="flex items-start gap-2">
+                                <Clock3
+                                  size={12}
+                                  className="mt-0.5 text-slate-500"
+                                />
+                                <span>{activeSession.geo.timezone}</span>
+                              </div>
+                            ) : null}
+                            {activeSession?.client ? (
+                              <div className="flex items-start gap-2">
+                                <Monitor
+                                  size={12}
+                                  className="mt-0.5 text-slate-500"
+                                />
+                                <span>
+                                  {[
+                                    activeSession.client.browser,
+                                    activeSession.client.os,
+                                    activeSession.client.screen,
+                                    activeSession.client.language,
+                                  ]
+                                    .filter(Boolean)
+                                    .join(" · ") || "-"}
+                                </span>
+                              </div>
+                            ) : null}
                           </div>
                         ) : null}
                         {key === "contactAttrs" ? (
@@ -2518,6 +2908,81 @@ export default function ConversationsView({
                             </div>
                           </div>
                         ) : null}
+                        {key === "escalations" ? (
+                          <div className="space-y-2">
+                            {ticketProviders.length === 0 ? (
+                              <p className="text-xs text-slate-500">
+                                Connect Zendesk or Jira in Settings →
+                                Integrations to escalate.
+                              </p>
+                            ) : (
+                              <>
+                                <select
+                                  value={ticketProvider}
+                                  onChange={(e) =>
+                                    setTicketProvider(e.target.value)
+                                  }
+                                  className="h-7 w-full rounded-md border border-slate-200 bg-white px-2 text-xs text-slate-700"
+                                >
+                                  {ticketProviders.map((provider) => (
+                                    <option key={provider} value={provider}>
+                                      {provider === "jira" ? "Jira" : "Zendesk"}
+                                    </option>
+                                  ))}
+                                </select>
+                                <Textarea
+                                  value={ticketNote}
+                                  onChange={(e) => setTicketNote(e.target.value)}
+                                  placeholder="Note for the ticket (optional)"
+                                  rows={2}
+                                  disabled={!activeId}
+                                  className="rounded-md border-slate-200 bg-white text-xs text-slate-800"
+                                />
+                                <Button
+                                  className="h-7 w-full rounded-md bg-slate-100 text-xs text-slate-800 hover:bg-slate-200"
+                                  onClick={submitEscalation}
+                                  disabled={!activeId || ticketBusy}
+                                >
+                                  {ticketBusy ? "Escalating…" : "Create ticket"}
+                                </Button>
+                              </>
+                            )}
+                            {ticketError ? (
+                              <p className="text-xs text-red-600">
+                                {ticketError}
+                              </p>
+                            ) : null}
+                            <div className="space-y-1.5">
+                              {tickets.map((ticket) => (
+                                <a
+                                  key={ticket.id}
+                                  href={ticket.url}
+                                  target="_blank"
+                                  rel="noreferrer"
+                                  className="flex items-start justify-between gap-2 rounded-md border border-slate-200 bg-white p-2 hover:bg-slate-50"
+                                >
+                                  <div className="min-w-0">
+                                    <p className="truncate text-xs font-medium text-slate-800">
+                                      {ticket.provider === "jira"
+                                        ? ticket.externalId
+                                        : `#${ticket.externalId}`}{" "}
+                                      · {ticket.subject}
+                                    </p>
+                                    <p className="mt-0.5 text-[10px] uppercase tracking-wide text-slate-400">
+                                      {ticket.status || "open"}
+                                      {ticket.resolved ? " · resolved" : ""} •{" "}
+                                      {formatTime(ticket.createdAt)}
+                                    </p>
+                                  </div>
+                                  <ExternalLink
+                                    size={12}
+                                    className="mt-0.5 shrink-0 text-slate-400"
+                                  />
+                                </a>
+                              ))}
+                            </div>
+                          </div>
+                        ) : null}
                         {key === "participants" ? (
                           <div className="space-y-1.5 text-xs text-slate-700">
                             <p>Assignee: {assigneeName}</p>
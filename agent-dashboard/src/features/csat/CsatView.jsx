@@ -7,7 +7,9 @@ const formatTime = (iso) => {
   return date.toLocaleTimeString([], { hour: "2-digit", minute: "2-digit" });
 };
 
-export default function CsatView({ csatReport }) {
+export default function CsatView({ csatReport, agents = [] }) {
+  const agentName = (agentId) =>
+    agents.find((agent) => agent.id === agentId)?.name || "Unknown agent";
   return (
     <div className="grid h-full min-h-0 grid-cols-[320px_1fr] gap-4 bg-slate-50 p-4 max-[1080px]:grid-cols-[1fr]">
       <aside className="rounded-xl border border-slate-200 bg-white p-4">
@@ -22,6 +24,24 @@ export default function CsatView({ csatReport }) {
             <p className="text-2xl font-semibold text-slate-900">{Number(csatReport.average || 0).toFixed(2)}</p>
           </div>
         </div>
+        {(csatReport.byAgent || []).length > 0 && (
+          <>
+            <h3 className="mt-5 text-sm font-semibold text-slate-900">By resolving agent</h3>
+            <div className="mt-3 space-y-2">
+              {csatReport.byAgent.map((row) => (
+                <div
+                  key={row.agentId}
+                  className="flex items-center justify-between rounded-lg border border-slate-200 bg-slate-50 px-3 py-2"
+                >
+                  <p className="truncate text-xs text-slate-700">{agentName(row.agentId)}</p>
+                  <p className="text-xs font-semibold text-slate-900">
+                    {Number(row.average || 0).toFixed(2)} · {row.count}
+                  </p>
+                </div>
+              ))}
+            </div>
+          </>
+        )}
       </aside>
       <section className="grid min-h-0 grid-rows-[auto_1fr] rounded-xl border border-slate-200 bg-white p-4">
         <h3 className="mb-3 text-sm font-semibold text-slate-900">CSAT submissions</h3>
@@ -31,6 +51,9 @@ export default function CsatView({ csatReport }) {
               <article key={survey.id} className="rounded-lg border border-slate-200 bg-slate-50 p-3">
                 <p className="text-sm font-semibold text-slate-900">Score: {survey.score}/5</p>
                 <p className="text-xs text-slate-600">{survey.comment || "No comment"}</p>
+                {survey.agentId && (
+                  <p className="text-xs text-slate-500">Resolved by {agentName(survey.agentId)}</p>
+                )}
                 <p className="text-[11px] text-slate-400">{formatTime(survey.submittedAt)}</p>
               </article>
             ))}
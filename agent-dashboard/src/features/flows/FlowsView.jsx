@@ -15,6 +15,7 @@ import {
 } from "@xyflow/react";
 import {
   Bot,
+  CalendarDays,
   Brain,
   ChevronDown,
   ChevronRight,
@@ -22,15 +23,20 @@ import {
   Clock,
   Code2,
   Copy,
+  Download,
   Eye,
   FileText,
   Globe,
   GripVertical,
   Hash,
   Image,
+  LayoutTemplate,
+  LifeBuoy,
   MessageSquare,
   MoreHorizontal,
   Pencil,
+  PhoneCall,
+  Plug,
   Plus,
   Puzzle,
   RefreshCw,
@@ -48,7 +54,7 @@ import {
   XCircle,
   Zap,
 } from "lucide-react";
-import { useState } from "react";
+import { useEffect, useRef, useState } from "react";
 
 /* ─── node type config & helpers ──────────────────────────── */
 
@@ -134,6 +140,12 @@ const NODE_COLORS = {
     icon: "#d97706",
     iconBg: "#fef3c7",
   },
+  business_hours: {
+    bg: "#fffbeb",
+    border: "#fde68a",
+    icon: "#d97706",
+    iconBg: "#fef3c7",
+  },
   assign: {
     bg: "#f0fdf4",
     border: "#bbf7d0",
@@ -152,6 +164,30 @@ const NODE_COLORS = {
     icon: "#eab308",
     iconBg: "#fef9c3",
   },
+  callback: {
+    bg: "#f0fdf4",
+    border: "#bbf7d0",
+    icon: "#16a34a",
+    iconBg: "#dcfce7",
+  },
+  book_meeting: {
+    bg: "#eef2ff",
+    border: "#c7d2fe",
+    icon: "#4f46e5",
+    iconBg: "#e0e7ff",
+  },
+  whatsapp_flow: {
+    bg: "#f0fdf4",
+    border: "#bbf7d0",
+    icon: "#16a34a",
+    iconBg: "#dcfce7",
+  },
+  escalate_ticket: {
+    bg: "#fff7ed",
+    border: "#fed7aa",
+    icon: "#ea580c",
+    iconBg: "#ffedd5",
+  },
   tag: {
     bg: "#fdf4ff",
     border: "#f0abfc",
@@ -176,6 +212,12 @@ const NODE_COLORS = {
     icon: "#ea580c",
     iconBg: "#ffedd5",
   },
+  action: {
+    bg: "#f5f3ff",
+    border: "#ddd6fe",
+    icon: "#7c3aed",
+    iconBg: "#ede9fe",
+  },
   start_flow: {
     bg: "#f0fdfa",
     border: "#5eead4",
@@ -201,13 +243,19 @@ const NODE_ICONS = {
   input_form: FileText,
   quick_input: Pencil,
   wait: Clock,
+  business_hours: Clock,
   assign: UserPlus,
   close_conversation: XCircle,
   csat: Star,
+  callback: PhoneCall,
+  book_meeting: CalendarDays,
+  whatsapp_flow: FileText,
+  escalate_ticket: LifeBuoy,
   tag: Tag,
   set_attribute: Hash,
   note: StickyNote,
   webhook: Send,
+  action: Plug,
   start_flow: Workflow,
 };
 
@@ -231,6 +279,41 @@ function displayTypeLabel(type) {
 }
 
 function outputPorts(type, data) {
+  if (type === "business_hours") {
+    return [
+      { id: "open", label: "Open" },
+      { id: "closed", label: "Closed" },
+    ];
+  }
+
+  if (type === "escalate_ticket") {
+    return [
+      { id: "created", label: "Created" },
+      { id: "failed", label: "Failed" },
+    ];
+  }
+
+  if (type === "action") {
+    return [
+      { id: "success", label: "Success" },
+      { id: "failed", label: "Failed" },
+    ];
+  }
+
+  if (type === "callback" || type === "book_meeting") {
+    return [
+      { id: "booked", label: "Booked" },
+      { id: "unavailable", label: "No slots" },
+    ];
+  }
+
+  if (type === "whatsapp_flow") {
+    return [
+      { id: "submitted", label: "Submitted" },
+      { id: "unavailable", label: "Not WhatsApp" },
+    ];
+  }
+
   if (type === "condition") {
     const custom = Array.isArray(data?.outputs)
       ? data.outputs.filter(Boolean)
@@ -257,6 +340,13 @@ function outputPorts(type, data) {
     }));
   }
 
+  if (type === "ai" || type === "webhook") {
+    return [
+      { id: "next", label: "Next" },
+      { id: "error", label: "Error" },
+    ];
+  }
+
   if (
     type === "buttons" &&
     Array.isArray(data?.buttons) &&
@@ -290,7 +380,9 @@ function DifyNode({ data, type, selected }) {
   const outputs = outputPorts(type, data);
   const isStart = type === "start" || type === "trigger";
   const isClassifier =
-    (type === "ai" || type === "question_classifier") && outputs.length > 0;
+    (type === "ai" || type === "question_classifier") &&
+    Array.isArray(data?.classes) &&
+    data.classes.length > 1;
   const hasMultiOutputs = outputs.length > 0;
 
   return (
@@ -525,6 +617,24 @@ function DifyNode({ data, type, selected }) {
             </div>
           )}
 
+          {/* BUSINESS HOURS body */}
+          {type === "business_hours" && (
+            <div className="space-y-1">
+              {(data?.windows || []).map((w, i) => (
+                <div key={i} className="flex items-center gap-1.5">
+                  <Clock size={12} className="text-amber-500" />
+                  <span className="truncate text-slate-600">
+                    {(w.days || []).join(", ") || "No days"} · {w.start}–
+                    {w.end}
+                  </span>
+                </div>
+              ))}
+              <p className="text-[10px] text-slate-400">
+                {data?.timezone || "Visitor time zone"}
+              </p>
+            </div>
+          )}
+
           {/* ASSIGN body */}
           {type === "assign" && (
             <div className="space-y-1">
@@ -557,6 +667,48 @@ function DifyNode({ data, type, selected }) {
             </div>
           )}
 
+          {/* CALLBACK body */}
+          {type === "callback" && (
+            <div className="space-y-1">
+              {data?.text && <p className="text-slate-500 mb-1">{data.text}</p>}
+              <div className="flex items-center gap-1.5">
+                <PhoneCall size={12} className="text-green-600" />
+                <span className="text-slate-600">
+                  Next {data?.days || 7} days
+                  {data?.askPhone === false ? "" : " · asks for phone"}
+                </span>
+              </div>
+            </div>
+          )}
+
+          {/* WHATSAPP FLOW body */}
+          {type === "whatsapp_flow" && (
+            <div className="space-y-1">
+              {data?.text && <p className="text-slate-500 mb-1">{data.text}</p>}
+              <div className="flex items-center gap-1.5">
+                <FileText size={12} className="text-green-600" />
+                <span className="truncate text-slate-600">
+                  {data?.flowId ? `Flow ${data.flowId}` : "Choose a WhatsApp Flow"}
+                  {data?.draft ? " · draft" : ""}
+                </span>
+              </div>
+            </div>
+          )}
+
+          {/* BOOK MEETING body */}
+          {type === "book_meeting" && (
+            <div className="space-y-1">
+              {data?.text && <p className="text-slate-500 mb-1">{data.text}</p>}
+              <div className="flex items-center gap-1.5">
+                <CalendarDays size={12} className="text-indigo-600" />
+                <span className="text-slate-600">
+                  {data?.durationMinutes || 30} min · next {data?.days || 7}{" "}
+                  days
+                </span>
+              </div>
+            </div>
+          )}
+
           {/* CSAT body */}
           {type === "csat" && (
             <div className="space-y-1">
@@ -574,6 +726,22 @@ function DifyNode({ data, type, selected }) {
             </div>
           )}
 
+          {/* ESCALATE TICKET body */}
+          {type === "escalate_ticket" && (
+            <div className="space-y-1">
+              <div className="flex items-center gap-1.5">
+                <LifeBuoy size={12} className="text-orange-600" />
+                <span className="text-slate-600">
+                  {data?.provider === "jira" ? "Jira issue" : "Zendesk ticket"}
+                  {data?.priority ? ` · ${data.priority}` : ""}
+                </span>
+              </div>
+              {data?.subject && (
+                <p className="truncate text-slate-500">{data.subject}</p>
+              )}
+            </div>
+          )}
+
           {/* TAG body */}
           {type === "tag" && (
             <div className="space-y-1">
@@ -636,6 +804,16 @@ function DifyNode({ data, type, selected }) {
             </div>
           )}
 
+          {/* ACTION body */}
+          {type === "action" && (
+            <div className="flex items-center gap-1.5">
+              <Plug size={12} className="text-violet-600" />
+              <span className="truncate text-slate-600">
+                {data?.accountName || "Choose a connector"}
+              </span>
+            </div>
+          )}
+
           {/* CONDITION body */}
           {type === "condition" &&
             (() => {
@@ -721,13 +899,19 @@ function DifyNode({ data, type, selected }) {
             type !== "select" &&
             type !== "input_form" &&
             type !== "wait" &&
+            type !== "business_hours" &&
             type !== "assign" &&
             type !== "close_conversation" &&
             type !== "csat" &&
+            type !== "callback" &&
+            type !== "book_meeting" &&
+            type !== "whatsapp_flow" &&
+            type !== "escalate_ticket" &&
             type !== "tag" &&
             type !== "set_attribute" &&
             type !== "note" &&
             type !== "webhook" &&
+            type !== "action" &&
             type !== "condition" && (
               <p className="text-slate-600 line-clamp-2">
                 {data?.text || data?.label || ""}
@@ -789,13 +973,18 @@ const FLOW_NODE_TYPES = {
   http: DifyNode,
   code: DifyNode,
   wait: DifyNode,
+  business_hours: DifyNode,
   assign: DifyNode,
   close_conversation: DifyNode,
   csat: DifyNode,
+  callback: DifyNode,
+  book_meeting: DifyNode,
+  escalate_ticket: DifyNode,
   tag: DifyNode,
   set_attribute: DifyNode,
   note: DifyNode,
   webhook: DifyNode,
+  action: DifyNode,
   start_flow: DifyNode,
 };
 
@@ -807,6 +996,12 @@ const CONTACT_VARIABLES = [
   { key: "contact.phone", displayName: "Contact Phone" },
   { key: "contact.company", displayName: "Contact Company" },
   { key: "contact.location", displayName: "Contact Location" },
+  { key: "contact.country", displayName: "Contact Country" },
+  { key: "contact.city", displayName: "Contact City" },
+  { key: "contact.timezone", displayName: "Contact Timezone" },
+  { key: "contact.browser", displayName: "Contact Browser" },
+  { key: "contact.os", displayName: "Contact OS" },
+  { key: "contact.language", displayName: "Contact Language" },
 ];
 
 function VariablePickerDropdown({
@@ -904,6 +1099,90 @@ function VariablePickerDropdown({
 
 /* ─── Right Sidebar — Settings Panel ─────────────────────── */
 
+function WeeklyWindowsEditor({ windows, onChange }) {
+  return (
+    <div className="space-y-2">
+      {windows.map((w, i) => {
+        const updateWindow = (patch) =>
+          onChange(
+            windows.map((item, idx) =>
+              idx === i ? { ...item, ...patch } : item,
+            ),
+          );
+        return (
+          <div
+            key={i}
+            className="space-y-1.5 rounded-lg border border-slate-200 p-2"
+          >
+            <div className="flex flex-wrap gap-1">
+              {["mon", "tue", "wed", "thu", "fri", "sat", "sun"].map((day) => {
+                const active = (w.days || []).includes(day);
+                return (
+                  <button
+                    key={day}
+                    type="button"
+                    onClick={() =>
+                      updateWindow({
+                        days: active
+                          ? (w.days || []).filter((d) => d !== day)
+                          : [...(w.days || []), day],
+                      })
+                    }
+                    className={`rounded px-1.5 py-0.5 text-[10px] font-medium uppercase ${
+                      active
+                        ? "bg-amber-100 text-amber-700"
+                        : "bg-slate-100 text-slate-400"
+                    }`}
+                  >
+                    {day}
+                  </button>
+                );
+              })}
+            </div>
+            <div className="flex items-center gap-2">
+              <Input
+                type="time"
+                value={w.start || "09:00"}
+                onChange={(e) => updateWindow({ start: e.target.value })}
+                className="flex-1 text-[12px]"
+              />
+              <span className="text-slate-400">–</span>
+              <Input
+                type="time"
+                value={w.end || "17:00"}
+                onChange={(e) => updateWindow({ end: e.target.value })}
+                className="flex-1 text-[12px]"
+              />
+              <button
+                type="button"
+                onClick={() => onChange(windows.filter((_, idx) => idx !== i))}
+                className="text-slate-400 hover:text-red-500"
+              >
+                <Trash2 size={12} />
+              </button>
+            </div>
+          </div>
+        );
+      })}
+      <button
+        onClick={() =>
+          onChange([
+            ...windows,
+            {
+              days: ["mon", "tue", "wed", "thu", "fri"],
+              start: "09:00",
+              end: "17:00",
+            },
+          ])
+        }
+        className="flex w-full items-center justify-center gap-1.5 rounded-lg border border-dashed border-slate-300 py-2 text-[11px] font-medium text-slate-500 transition-colors hover:border-blue-400 hover:bg-blue-50 hover:text-blue-600"
+      >
+        <Plus size={12} /> Add Window
+      </button>
+    </div>
+  );
+}
+
 function SettingsPanel({
   selectedNode,
   updateSelectedNodeData,
@@ -926,6 +1205,23 @@ function SettingsPanel({
   const [newAttrKey, setNewAttrKey] = useState("");
   const [newAttrDesc, setNewAttrDesc] = useState("");
   const [newAttrModel, setNewAttrModel] = useState("contact");
+  const [connectorCatalog, setConnectorCatalog] = useState(null);
+
+  const selectedType = selectedNode?.type;
+  useEffect(() => {
+    if (selectedType !== "action" || connectorCatalog || !token) return;
+    Promise.all([
+      apiFetch("/api/connectors", token),
+      apiFetch("/api/tenant/connector-accounts", token),
+    ])
+      .then(([defs, accounts]) =>
+        setConnectorCatalog({
+          connectors: defs.connectors ?? [],
+          accounts: accounts.accounts ?? [],
+        }),
+      )
+      .catch(() => setConnectorCatalog({ connectors: [], accounts: [] }));
+  }, [selectedType, connectorCatalog, token]);
 
   if (!selectedNode) {
     return (
@@ -1257,6 +1553,56 @@ function SettingsPanel({
             </div>
           )}
 
+          {/* ── Low confidence ── */}
+          {type === "ai" && (
+            <div className="space-y-3">
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Confidence threshold
+                </label>
+                <Input
+                  type="number"
+                  min={0}
+                  max={1}
+                  step={0.05}
+                  value={data?.confidenceThreshold ?? ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({
+                      confidenceThreshold:
+                        e.target.value === ""
+                          ? undefined
+                          : Number(e.target.value),
+                    })
+                  }
+                  placeholder="Workspace default"
+                  className="text-[12px]"
+                />
+                <p className="mt-1 text-[10px] text-slate-400">
+                  Answers the AI is less sure of are not sent. 0 turns the
+                  check off for this node.
+                </p>
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  When below threshold
+                </label>
+                <select
+                  className="w-full rounded-lg border border-slate-200 bg-white px-3 py-2 text-[12px]"
+                  value={data?.lowConfidenceAction || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({
+                      lowConfidenceAction: e.target.value || undefined,
+                    })
+                  }
+                >
+                  <option value="">Workspace default</option>
+                  <option value="clarify">Ask a clarifying question</option>
+                  <option value="handover">Hand over to an agent</option>
+                </select>
+              </div>
+            </div>
+          )}
+
           {/* ── LLM Settings ── */}
           {type === "llm" && (
             <div className="space-y-3">
@@ -1513,6 +1859,20 @@ function SettingsPanel({
                                 <option value="contact.location">
                                   Location
                                 </option>
+                                <option value="contact.country">
+                                  Country code
+                                </option>
+                                <option value="contact.city">City</option>
+                                <option value="contact.timezone">
+                                  Timezone
+                                </option>
+                                <option value="contact.browser">Browser</option>
+                                <option value="contact.os">
+                                  Operating system
+                                </option>
+                                <option value="contact.language">
+                                  Language
+                                </option>
                               </optgroup>
                               <optgroup label="Custom">
                                 <option value="contact_attribute">
@@ -1522,6 +1882,19 @@ function SettingsPanel({
                                   Conversation attribute…
                                 </option>
                               </optgroup>
+                              {(flowInputVariables || []).some(
+                                (v) => v.key,
+                              ) && (
+                                <optgroup label="Flow Variables">
+                                  {flowInputVariables
+                                    .filter((v) => v.key)
+                                    .map((v) => (
+                                      <option key={v.key} value={`var.${v.key}`}>
+                                        {v.label || v.key}
+                                      </option>
+                                    ))}
+                                </optgroup>
+                              )}
                               {(attributeDefs || []).filter(
                                 (d) => d.attributeModel === "contact",
                               ).length > 0 && (
@@ -1607,9 +1980,19 @@ function SettingsPanel({
                                   greater than
                                 </option>
                                 <option value="less_than">less than</option>
+                                <option value="greater_or_equal">
+                                  at least
+                                </option>
+                                <option value="less_or_equal">at most</option>
+                                <option value="before">is before</option>
+                                <option value="after">is after</option>
+                                <option value="is_true">is yes</option>
+                                <option value="is_false">is no</option>
                               </select>
                               {rule.operator !== "is_empty" &&
-                                rule.operator !== "is_not_empty" && (
+                                rule.operator !== "is_not_empty" &&
+                                rule.operator !== "is_true" &&
+                                rule.operator !== "is_false" && (
                                   <Input
                                     value={rule.value || ""}
                                     onChange={(e) => {
@@ -2166,6 +2549,155 @@ function SettingsPanel({
                       </div>
                     </div>
                   )}
+                  <div>
+                    <label className="mb-1 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                      Targeting
+                    </label>
+                    <p className="mb-2 text-[10px] text-slate-400">
+                      Only run for visitors matching these rules. Use * as a
+                      wildcard with "matches pattern".
+                    </p>
+                    <div className="space-y-2">
+                      {(data?.rules || []).map((rule, i) => (
+                        <div
+                          key={i}
+                          className="space-y-1.5 rounded-lg border border-slate-200 bg-slate-50 p-2.5"
+                        >
+                          {i > 0 && (
+                            <div className="flex justify-center pb-1">
+                              <select
+                                className="rounded border border-slate-200 bg-white px-2 py-0.5 text-[10px] font-medium text-amber-700"
+                                value={data?.logicOperator || "and"}
+                                onChange={(e) =>
+                                  updateSelectedNodeData({
+                                    logicOperator: e.target.value,
+                                  })
+                                }
+                              >
+                                <option value="and">AND</option>
+                                <option value="or">OR</option>
+                              </select>
+                            </div>
+                          )}
+                          <select
+                            className="w-full rounded-lg border border-slate-200 bg-white px-2 py-1.5 text-[11px] text-slate-700"
+                            value={rule.attribute || "page_url"}
+                            onChange={(e) => {
+                              const rules = [...(data.rules || [])];
+                              rules[i] = {
+                                ...rules[i],
+                                attribute: e.target.value,
+                              };
+                              updateSelectedNodeData({ rules });
+                            }}
+                          >
+                            <option value="page_url">Page URL</option>
+                            <option value="referrer">Referrer</option>
+                            <option value="country">Country code</option>
+                            <option value="city">City</option>
+                            <option value="timezone">Timezone</option>
+                            <option value="browser">Browser</option>
+                            <option value="os">Operating system</option>
+                            <option value="language">Language</option>
+                            <option value="device_type">
+                              Device (desktop, mobile, tablet)
+                            </option>
+                            <option value="visitor_type">
+                              Visitor (new, returning)
+                            </option>
+                            <option value="visitor_attribute">
+                              Embed attribute…
+                            </option>
+                          </select>
+                          {rule.attribute === "visitor_attribute" && (
+                            <Input
+                              value={rule.attributeKey || ""}
+                              onChange={(e) => {
+                                const rules = [...(data.rules || [])];
+                                rules[i] = {
+                                  ...rules[i],
+                                  attributeKey: e.target.value,
+                                };
+                                updateSelectedNodeData({ rules });
+                              }}
+                              placeholder="Attribute key"
+                              className="text-[11px]"
+                            />
+                          )}
+                          <div className="grid grid-cols-2 gap-1.5">
+                            <select
+                              className="w-full rounded-lg border border-slate-200 bg-white px-2 py-1.5 text-[11px] text-slate-700"
+                              value={rule.operator || "equals"}
+                              onChange={(e) => {
+                                const rules = [...(data.rules || [])];
+                                rules[i] = {
+                                  ...rules[i],
+                                  operator: e.target.value,
+                                };
+                                updateSelectedNodeData({ rules });
+                              }}
+                            >
+                              <option value="equals">equals</option>
+                              <option value="not_equals">does not equal</option>
+                              <option value="contains">contains</option>
+                              <option value="not_contains">
+                                does not contain
+                              </option>
+                              <option value="starts_with">starts with</option>
+                              <option value="matches">matches pattern</option>
+                              <option value="is_empty">is empty</option>
+                              <option value="is_not_empty">is not empty</option>
+                            </select>
+                            {rule.operator !== "is_empty" &&
+                              rule.operator !== "is_not_empty" && (
+                                <Input
+                                  value={rule.value || ""}
+                                  onChange={(e) => {
+                                    const rules = [...(data.rules || [])];
+                                    rules[i] = {
+                                      ...rules[i],
+                                      value: e.target.value,
+                                    };
+                                    updateSelectedNodeData({ rules });
+                                  }}
+                                  placeholder="Value"
+                                  className="text-[11px]"
+                                />
+                              )}
+                          </div>
+                          <div className="flex justify-end">
+                            <button
+                              onClick={() => {
+                                const rules = [...(data.rules || [])];
+                                rules.splice(i, 1);
+                                updateSelectedNodeData({ rules });
+                              }}
+                              className="rounded p-1 text-slate-400 hover:bg-red-50 hover:text-red-500"
+                            >
+                              <Trash2 size={12} />
+                            </button>
+                          </div>
+                        </div>
+                      ))}
+                      <button
+                        onClick={() =>
+                          updateSelectedNodeData({
+                            rules: [
+                              ...(data.rules || []),
+                              {
+                                attribute: "page_url",
+                                operator: "contains",
+                                value: "",
+                              },
+                            ],
+                          })
+                        }
+                        className="flex w-full items-center justify-center gap-1.5 rounded-lg border border-dashed border-slate-300 py-2 text-[11px] font-medium text-slate-500 transition-colors hover:border-blue-400 hover:bg-blue-50 hover:text-blue-600"
+                      >
+                        <Plus size={12} /> Add Rule
+                      </button>
+                    </div>
+                  </div>
                 </>
               )}
               {/* ── Flow Input Variables ── */}
@@ -2174,8 +2706,9 @@ function SettingsPanel({
                   Input Variables
                 </label>
                 <p className="mb-2 text-[10px] text-slate-400">
-                  Define variables other flows must provide when calling this
-                  flow.
+                  Declare the variables this flow uses and their types. Values
+                  from forms and AI are checked against the type; required ones
+                  must be provided when another flow calls this one.
                 </p>
                 <div className="space-y-2">
                   {(flowInputVariables || []).map((v, i) => (
@@ -2200,6 +2733,21 @@ function SettingsPanel({
                         placeholder="Label"
                         className="flex-1 text-[12px]"
                       />
+                      <select
+                        className="rounded-lg border border-slate-200 bg-white px-1.5 py-1.5 text-[11px] text-slate-700"
+                        value={v.type || "string"}
+                        onChange={(e) => {
+                          const next = [...flowInputVariables];
+                          next[i] = { ...next[i], type: e.target.value };
+                          setFlowInputVariables(next);
+                        }}
+                      >
+                        <option value="string">Text</option>
+                        <option value="number">Number</option>
+                        <option value="boolean">Yes/No</option>
+                        <option value="datetime">Date</option>
+                        <option value="list">List</option>
+                      </select>
                       <label className="flex items-center gap-1 text-[10px] text-slate-500 whitespace-nowrap">
                         <input
                           type="checkbox"
@@ -2232,7 +2780,12 @@ function SettingsPanel({
                     onClick={() =>
                       setFlowInputVariables([
                         ...(flowInputVariables || []),
-                        { key: "", label: "", required: false },
+                        {
+                          key: "",
+                          label: "",
+                          required: false,
+                          type: "string",
+                        },
                       ])
                     }
                     className="flex w-full items-center justify-center gap-1.5 rounded-lg border border-dashed border-slate-300 py-2 text-[11px] font-medium text-slate-500 transition-colors hover:border-teal-400 hover:bg-teal-50 hover:text-teal-600"
@@ -2460,8 +3013,8 @@ function SettingsPanel({
             </div>
           )}
 
-          {/* ── Assign Settings ── */}
-          {type === "assign" && (
+          {/* ── Business Hours Settings ── */}
+          {type === "business_hours" && (
             <div className="space-y-3">
               <div>
                 <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
@@ -2472,19 +3025,64 @@ function SettingsPanel({
                   onChange={(e) =>
                     updateSelectedNodeData({ label: e.target.value })
                   }
-                  placeholder="Assign"
+                  placeholder="Business Hours"
                   className="text-[12px]"
                 />
               </div>
               <div>
                 <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
-                  Assign To
+                  Time zone
                 </label>
-                <select
-                  className="w-full rounded-lg border border-slate-200 bg-white px-3 py-2 text-[12px]"
-                  value={data?.assignTo || "team"}
+                <Input
+                  value={data?.timezone || ""}
                   onChange={(e) =>
-                    updateSelectedNodeData({ assignTo: e.target.value })
+                    updateSelectedNodeData({ timezone: e.target.value })
+                  }
+                  placeholder="Visitor (e.g. America/Sao_Paulo to pin)"
+                  className="text-[12px]"
+                />
+                <p className="mt-1 text-[10px] text-slate-400">
+                  Leave empty to use the visitor's time zone, falling back to
+                  the workspace default.
+                </p>
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Open windows
+                </label>
+                <WeeklyWindowsEditor
+                  windows={data?.windows || []}
+                  onChange={(windows) => updateSelectedNodeData({ windows })}
+                />
+              </div>
+            </div>
+          )}
+
+          {/* ── Assign Settings ── */}
+          {type === "assign" && (
+            <div className="space-y-3">
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Label
+                </label>
+                <Input
+                  value={data?.label || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ label: e.target.value })
+                  }
+                  placeholder="Assign"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Assign To
+                </label>
+                <select
+                  className="w-full rounded-lg border border-slate-200 bg-white px-3 py-2 text-[12px]"
+                  value={data?.assignTo || "team"}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ assignTo: e.target.value })
                   }
                 >
                   <option value="team">Team</option>
@@ -2506,6 +3104,21 @@ function SettingsPanel({
                   />
                 </div>
               )}
+              {data?.assignTo !== "agent" && (
+                <div>
+                  <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                    Required Skills
+                  </label>
+                  <Input
+                    value={data?.requiredSkills || ""}
+                    onChange={(e) =>
+                      updateSelectedNodeData({ requiredSkills: e.target.value })
+                    }
+                    placeholder="e.g. language:es, product:billing"
+                    className="text-[12px]"
+                  />
+                </div>
+              )}
               {data?.assignTo === "agent" && (
                 <div>
                   <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
@@ -2639,6 +3252,482 @@ function SettingsPanel({
             </div>
           )}
 
+          {/* ── WhatsApp Flow Settings ── */}
+          {type === "whatsapp_flow" && (
+            <div className="space-y-3">
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Label
+                </label>
+                <Input
+                  value={data?.label || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ label: e.target.value })
+                  }
+                  placeholder="WhatsApp Form"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Message Text
+                </label>
+                <div className="relative">
+                  <Textarea
+                    rows={2}
+                    value={data?.text || ""}
+                    onChange={(e) =>
+                      updateSelectedNodeData({ text: e.target.value })
+                    }
+                    placeholder="Please fill in this form."
+                    className="pr-8 text-[12px]"
+                  />
+                  <VariablePickerDropdown
+                    attributeDefs={attributeDefs}
+                    flowInputVariables={flowInputVariables}
+                    onSelect={(varKey) => {
+                      const cur = data?.text || "";
+                      updateSelectedNodeData({ text: cur + `{{${varKey}}}` });
+                    }}
+                  />
+                </div>
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Flow ID
+                </label>
+                <Input
+                  value={data?.flowId || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ flowId: e.target.value.trim() })
+                  }
+                  placeholder="WhatsApp Flow ID from WhatsApp Manager"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  First Screen
+                </label>
+                <Input
+                  value={data?.screen || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ screen: e.target.value.trim() })
+                  }
+                  placeholder="Defaults to the flow's first screen"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Button Label
+                </label>
+                <Input
+                  value={data?.cta || ""}
+                  maxLength={20}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ cta: e.target.value })
+                  }
+                  placeholder="Open form"
+                  className="text-[12px]"
+                />
+              </div>
+              <label className="flex items-center gap-2 text-[12px] text-slate-600">
+                <input
+                  type="checkbox"
+                  checked={Boolean(data?.draft)}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ draft: e.target.checked })
+                  }
+                />
+                Send the draft version (testing)
+              </label>
+              <p className="text-[11px] text-slate-400">
+                Continues on Submitted with each answer as{" "}
+                {"{{form.<field>}}"}. Conversations outside WhatsApp take Not
+                WhatsApp.
+              </p>
+            </div>
+          )}
+
+          {/* ── Callback Settings ── */}
+          {type === "callback" && (
+            <div className="space-y-3">
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Label
+                </label>
+                <Input
+                  value={data?.label || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ label: e.target.value })
+                  }
+                  placeholder="Callback"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Message Text
+                </label>
+                <div className="relative">
+                  <Textarea
+                    rows={2}
+                    value={data?.text || ""}
+                    onChange={(e) =>
+                      updateSelectedNodeData({ text: e.target.value })
+                    }
+                    placeholder="When should we call you back?"
+                    className="pr-8 text-[12px]"
+                  />
+                  <VariablePickerDropdown
+                    attributeDefs={attributeDefs}
+                    flowInputVariables={flowInputVariables}
+                    onSelect={(varKey) => {
+                      const cur = data?.text || "";
+                      updateSelectedNodeData({ text: cur + `{{${varKey}}}` });
+                    }}
+                  />
+                </div>
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Days Ahead
+                </label>
+                <Input
+                  type="number"
+                  min={1}
+                  max={14}
+                  value={data?.days ?? 7}
+                  onChange={(e) =>
+                    updateSelectedNodeData({
+                      days: Math.min(14, Math.max(1, Number(e.target.value) || 7)),
+                    })
+                  }
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Button Label
+                </label>
+                <Input
+                  value={data?.submitLabel || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ submitLabel: e.target.value })
+                  }
+                  placeholder="Book callback"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  No Slots Message
+                </label>
+                <Textarea
+                  rows={2}
+                  value={data?.unavailableText || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ unavailableText: e.target.value })
+                  }
+                  placeholder="Sorry, there are no callback times available right now."
+                  className="text-[12px]"
+                />
+                <p className="mt-1 text-[10px] text-slate-400">
+                  Sent when no scheduled agent is free and the "No slots"
+                  output is not connected.
+                </p>
+              </div>
+              <div className="flex items-center justify-between rounded-lg border border-slate-100 bg-slate-50 px-3 py-2">
+                <div>
+                  <p className="text-[11px] font-medium text-slate-700">
+                    Ask for phone number
+                  </p>
+                  <p className="text-[10px] text-slate-400">
+                    Falls back to the contact's phone when empty
+                  </p>
+                </div>
+                <button
+                  onClick={() =>
+                    updateSelectedNodeData({
+                      askPhone: data?.askPhone === false,
+                    })
+                  }
+                  className={`relative inline-flex h-5 w-9 items-center rounded-full transition-colors ${data?.askPhone !== false ? "bg-blue-500" : "bg-slate-300"}`}
+                >
+                  <span
+                    className={`inline-block h-3.5 w-3.5 rounded-full bg-white transition-transform ${data?.askPhone !== false ? "translate-x-[18px]" : "translate-x-[3px]"}`}
+                  />
+                </button>
+              </div>
+              <p className="text-[10px] text-slate-400">
+                Only agents with a weekly schedule take callbacks. After
+                booking, {"{{callback.time}}"}, {"{{callback.agent}}"} and{" "}
+                {"{{callback.phone}}"} are available.
+              </p>
+            </div>
+          )}
+
+          {/* ── Book Meeting Settings ── */}
+          {type === "book_meeting" && (
+            <div className="space-y-3">
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Label
+                </label>
+                <Input
+                  value={data?.label || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ label: e.target.value })
+                  }
+                  placeholder="Book Meeting"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Message Text
+                </label>
+                <div className="relative">
+                  <Textarea
+                    rows={2}
+                    value={data?.text || ""}
+                    onChange={(e) =>
+                      updateSelectedNodeData({ text: e.target.value })
+                    }
+                    placeholder="Pick a time that works for you."
+                    className="pr-8 text-[12px]"
+                  />
+                  <VariablePickerDropdown
+                    attributeDefs={attributeDefs}
+                    flowInputVariables={flowInputVariables}
+                    onSelect={(varKey) => {
+                      const cur = data?.text || "";
+                      updateSelectedNodeData({ text: cur + `{{${varKey}}}` });
+                    }}
+                  />
+                </div>
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Event Title
+                </label>
+                <Input
+                  value={data?.title || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ title: e.target.value })
+                  }
+                  placeholder="Meeting"
+                  className="text-[12px]"
+                />
+              </div>
+              <div className="grid grid-cols-2 gap-2">
+                <div>
+                  <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                    Minutes
+                  </label>
+                  <Input
+                    type="number"
+                    min={15}
+                    max={240}
+                    step={15}
+                    value={data?.durationMinutes ?? 30}
+                    onChange={(e) =>
+                      updateSelectedNodeData({
+                        durationMinutes: Math.min(
+                          240,
+                          Math.max(15, Number(e.target.value) || 30),
+                        ),
+                      })
+                    }
+                    className="text-[12px]"
+                  />
+                </div>
+                <div>
+                  <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                    Days Ahead
+                  </label>
+                  <Input
+                    type="number"
+                    min={1}
+                    max={30}
+                    value={data?.days ?? 7}
+                    onChange={(e) =>
+                      updateSelectedNodeData({
+                        days: Math.min(30, Math.max(1, Number(e.target.value) || 7)),
+                      })
+                    }
+                    className="text-[12px]"
+                  />
+                </div>
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Button Label
+                </label>
+                <Input
+                  value={data?.submitLabel || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ submitLabel: e.target.value })
+                  }
+                  placeholder="Book meeting"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  No Slots Message
+                </label>
+                <Textarea
+                  rows={2}
+                  value={data?.unavailableText || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ unavailableText: e.target.value })
+                  }
+                  placeholder="Sorry, there are no meeting times available right now."
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Default hours
+                </label>
+                <WeeklyWindowsEditor
+                  windows={data?.hours || []}
+                  onChange={(hours) => updateSelectedNodeData({ hours })}
+                />
+                <p className="mt-1 text-[10px] text-slate-400">
+                  Used for agents without a weekly schedule, in the workspace
+                  time zone. Empty means weekdays 09:00–17:00.
+                </p>
+              </div>
+              <div className="flex items-center justify-between rounded-lg border border-slate-100 bg-slate-50 px-3 py-2">
+                <div>
+                  <p className="text-[11px] font-medium text-slate-700">
+                    Ask for name
+                  </p>
+                  <p className="text-[10px] text-slate-400">
+                    Falls back to the contact's name when empty
+                  </p>
+                </div>
+                <button
+                  onClick={() =>
+                    updateSelectedNodeData({
+                      askName: data?.askName === false,
+                    })
+                  }
+                  className={`relative inline-flex h-5 w-9 items-center rounded-full transition-colors ${data?.askName !== false ? "bg-blue-500" : "bg-slate-300"}`}
+                >
+                  <span
+                    className={`inline-block h-3.5 w-3.5 rounded-full bg-white transition-transform ${data?.askName !== false ? "translate-x-[18px]" : "translate-x-[3px]"}`}
+                  />
+                </button>
+              </div>
+              <p className="text-[10px] text-slate-400">
+                Only agents with a connected Google or Outlook calendar are
+                offered. After booking, {"{{meeting.time}}"},{" "}
+                {"{{meeting.agent}}"} and {"{{meeting.manageUrl}}"} are
+                available.
+              </p>
+            </div>
+          )}
+
+          {/* ── Escalate Ticket Settings ── */}
+          {type === "escalate_ticket" && (
+            <div className="space-y-3">
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Label
+                </label>
+                <Input
+                  value={data?.label || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ label: e.target.value })
+                  }
+                  placeholder="Escalate Ticket"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Provider
+                </label>
+                <select
+                  className="w-full rounded-lg border border-slate-200 bg-white px-3 py-2 text-[12px]"
+                  value={data?.provider || "zendesk"}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ provider: e.target.value })
+                  }
+                >
+                  <option value="zendesk">Zendesk</option>
+                  <option value="jira">Jira</option>
+                </select>
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Subject
+                </label>
+                <Input
+                  value={data?.subject || ""}
+                  onChange={(e) =>
+                    updateSelectedNodeData({ subject: e.target.value })
+                  }
+                  placeholder="Chat escalation: {{contact.name}}"
+                  className="text-[12px]"
+                />
+              </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Note
+                </label>
+                <div className="relative">
+                  <Textarea
+                    rows={2}
+                    value={data?.note || ""}
+                    onChange={(e) =>
+                      updateSelectedNodeData({ note: e.target.value })
+                    }
+                    placeholder="Added below the conversation summary"
+                    className="pr-8 text-[12px]"
+                  />
+                  <VariablePickerDropdown
+                    attributeDefs={attributeDefs}
+                    flowInputVariables={flowInputVariables}
+                    onSelect={(varKey) => {
+                      const cur = data?.note || "";
+                      updateSelectedNodeData({ note: cur + `{{${varKey}}}` });
+                    }}
+                  />
+                </div>
+              </div>
+              {(data?.provider || "zendesk") === "zendesk" && (
+                <div>
+                  <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                    Priority
+                  </label>
+                  <select
+                    className="w-full rounded-lg border border-slate-200 bg-white px-3 py-2 text-[12px]"
+                    value={data?.priority || ""}
+                    onChange={(e) =>
+                      updateSelectedNodeData({ priority: e.target.value })
+                    }
+                  >
+                    <option value="">Default</option>
+                    <option value="low">Low</option>
+                    <option value="normal">Normal</option>
+                    <option value="high">High</option>
+                    <option value="urgent">Urgent</option>
+                  </select>
+                </div>
+              )}
+              <p className="text-[10px] text-slate-400">
+                Connect the provider in Settings → Integrations. After
+                creation, {"{{ticket.id}}"} and {"{{ticket.url}}"} are
+                available.
+              </p>
+            </div>
+          )}
+
           {/* ── Tag Settings ── */}
           {type === "tag" && (
             <div className="space-y-3">
@@ -2921,6 +4010,122 @@ function SettingsPanel({
             </div>
           )}
 
+          {/* ── Action Settings ── */}
+          {type === "action" &&
+            (() => {
+              const accounts = connectorCatalog?.accounts ?? [];
+              const account = accounts.find((a) => a.id === data?.accountId);
+              const definition = (connectorCatalog?.connectors ?? []).find(
+                (c) => c.key === account?.connector,
+              );
+              const params = data?.params || {};
+              return (
+                <div className="space-y-3">
+                  <div>
+                    <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                      Label
+                    </label>
+                    <Input
+                      value={data?.label || ""}
+                      onChange={(e) =>
+                        updateSelectedNodeData({ label: e.target.value })
+                      }
+                      placeholder="Action"
+                      className="text-[12px]"
+                    />
+                  </div>
+                  <div>
+                    <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                      Connector account
+                    </label>
+                    <select
+                      className="w-full rounded-lg border border-slate-200 bg-white px-3 py-2 text-[12px]"
+                      value={data?.accountId || ""}
+                      onChange={(e) => {
+                        const next = accounts.find(
+                          (a) => a.id === e.target.value,
+                        );
+                        updateSelectedNodeData({
+                          accountId: e.target.value,
+                          accountName: next?.name || "",
+                          params: {},
+                        });
+                      }}
+                    >
+                      <option value="">Select an account…</option>
+                      {accounts.map((a) => (
+                        <option key={a.id} value={a.id}>
+                          {a.name} ({a.connector})
+                        </option>
+                      ))}
+                    </select>
+                    {connectorCatalog && accounts.length === 0 && (
+                      <p className="mt-1 text-[11px] text-slate-400">
+                        Add a connector account under Settings → Integrations.
+                      </p>
+                    )}
+                  </div>
+                  {(definition?.actionFields ?? []).map((field) => (
+                    <div key={field.key}>
+                      <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                        {field.label}
+                        {field.required ? " *" : ""}
+                      </label>
+                      <div className="relative">
+                        {field.kind === "textarea" ? (
+                          <Textarea
+                            rows={3}
+                            value={params[field.key] || ""}
+                            onChange={(e) =>
+                              updateSelectedNodeData({
+                                params: {
+                                  ...params,
+                                  [field.key]: e.target.value,
+                                },
+                              })
+                            }
+                            placeholder={field.placeholder}
+                            className="pr-8 text-[12px]"
+                          />
+                        ) : (
+                          <Input
+                            value={params[field.key] || ""}
+                            onChange={(e) =>
+                              updateSelectedNodeData({
+                                params: {
+                                  ...params,
+                                  [field.key]: e.target.value,
+                                },
+                              })
+                            }
+                            placeholder={field.placeholder}
+                            className="pr-8 text-[12px]"
+                          />
+                        )}
+                        <VariablePickerDropdown
+                          attributeDefs={attributeDefs}
+                          flowInputVariables={flowInputVariables}
+                          onSelect={(varKey) =>
+                            updateSelectedNodeData({
+                              params: {
+                                ...params,
+                                [field.key]:
+                                  (params[field.key] || "") + `{{${varKey}}}`,
+                              },
+                            })
+                          }
+                        />
+                      </div>
+                    </div>
+                  ))}
+                  <p className="text-[11px] text-slate-400">
+                    Continues on Success or Failed. {"{{action.result}}"} and{" "}
+                    {"{{action.error}}"} are available afterwards.
+                  </p>
+                </div>
+              );
+            })()}
+
           {/* ── Webhook Settings ── */}
           {type === "webhook" && (
             <div className="space-y-3">
@@ -2996,6 +4201,27 @@ function SettingsPanel({
                   className="font-mono text-[11px]"
                 />
               </div>
+              <div>
+                <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
+                  Timeout (ms)
+                </label>
+                <Input
+                  type="number"
+                  min={1000}
+                  max={60000}
+                  step={1000}
+                  value={data?.timeoutMs ?? 10000}
+                  onChange={(e) =>
+                    updateSelectedNodeData({
+                      timeoutMs: Number(e.target.value) || 10000,
+                    })
+                  }
+                  className="text-[12px]"
+                />
+                <p className="mt-1 text-[10px] text-slate-400">
+                  Failed or timed-out calls follow the Error output.
+                </p>
+              </div>
             </div>
           )}
 
@@ -3006,7 +4232,10 @@ function SettingsPanel({
             type === "select" ||
             type === "input_form" ||
             type === "quick_input" ||
-            type === "csat") && (
+            type === "csat" ||
+            type === "callback" ||
+            type === "book_meeting" ||
+            type === "whatsapp_flow") && (
             <div>
               <label className="mb-1.5 block text-[11px] font-semibold uppercase tracking-wider text-slate-500">
                 Typing Delay (ms)
@@ -3236,6 +4465,8 @@ function FlowTopBar({
   setFlowAiTool,
   flowAiToolDescription,
   setFlowAiToolDescription,
+  flowMaxSteps,
+  setFlowMaxSteps,
 }) {
   const [showMeta, setShowMeta] = useState(false);
   const timeStr = new Date().toLocaleTimeString([], {
@@ -3321,6 +4552,28 @@ function FlowTopBar({
                 </p>
               </div>
             )}
+            <div>
+              <label className="mb-1 block text-[10px] font-semibold uppercase tracking-wider text-slate-400">
+                Step limit
+              </label>
+              <Input
+                type="number"
+                min={0}
+                max={500}
+                value={flowMaxSteps || ""}
+                onChange={(e) =>
+                  setFlowMaxSteps(
+                    Math.min(500, Math.max(0, Number(e.target.value) || 0)),
+                  )
+                }
+                placeholder="24"
+                className="text-[12px]"
+              />
+              <p className="mt-1 text-[10px] text-slate-400">
+                Most steps one run may take before it stops. Leave empty for
+                the default.
+              </p>
+            </div>
             <div className="flex items-center justify-between border-t border-slate-100 pt-2">
               <button
                 onClick={() => {
@@ -3378,13 +4631,19 @@ function AddNodePalette({ addFlowNode }) {
     { type: "input_form", label: "Input Form", icon: FileText },
     { type: "quick_input", label: "Quick Input", icon: Pencil },
     { type: "csat", label: "CSAT Rating", icon: Star },
+    { type: "callback", label: "Callback", icon: PhoneCall },
+    { type: "book_meeting", label: "Book Meeting", icon: CalendarDays },
+    { type: "whatsapp_flow", label: "WhatsApp Form", icon: FileText },
     { type: "wait", label: "Wait / Snooze", icon: Clock },
+    { type: "business_hours", label: "Business Hours", icon: Clock },
     { type: "assign", label: "Assign", icon: UserPlus },
     { type: "close_conversation", label: "Close Conversation", icon: XCircle },
+    { type: "escalate_ticket", label: "Escalate Ticket", icon: LifeBuoy },
     { type: "tag", label: "Tag", icon: Tag },
     { type: "set_attribute", label: "Set Attribute", icon: Hash },
     { type: "note", label: "Note", icon: StickyNote },
     { type: "webhook", label: "Webhook", icon: Send },
+    { type: "action", label: "Action", icon: Plug },
     { type: "start_flow", label: "Start Flow", icon: Workflow },
     { type: "http", label: "HTTP Request", icon: Globe },
     { type: "code", label: "Code", icon: Code2 },
@@ -3447,6 +4706,10 @@ function AddNodePalette({ addFlowNode }) {
 export default function FlowsView({
   flows,
   createFlow,
+  flowTemplates,
+  createFlowFromTemplate,
+  exportFlow,
+  importFlow,
   activeFlowId,
   setActiveFlowId,
   loadFlowIntoEditor,
@@ -3480,20 +4743,90 @@ export default function FlowsView({
   setFlowAiTool,
   flowAiToolDescription,
   setFlowAiToolDescription,
+  flowMaxSteps,
+  setFlowMaxSteps,
 }) {
+  const importInputRef = useRef(null);
+  const [templatesOpen, setTemplatesOpen] = useState(false);
+
   return (
     <div className="grid h-full min-h-0 grid-cols-[260px_1fr_340px] bg-[#f0f2f7] max-[1200px]:grid-cols-[1fr]">
       {/* ── Left: Flow list ── */}
       <aside className="grid min-h-0 grid-rows-[auto_1fr] border-r border-slate-200 bg-white max-[1200px]:hidden">
         <div className="flex items-center justify-between border-b border-slate-100 px-3 py-3">
           <h3 className="text-[13px] font-semibold text-slate-700">Flows</h3>
-          <Button
-            size="sm"
-            onClick={createFlow}
-            className="h-7 rounded-lg bg-blue-500 px-2.5 text-[11px] text-white hover:bg-blue-600"
-          >
-            <Plus size={12} className="mr-1" /> New
-          </Button>
+          <div className="flex items-center gap-1">
+            <input
+              ref={importInputRef}
+              type="file"
+              accept="application/json,.json"
+              className="hidden"
+              onChange={(e) => {
+                const file = e.target.files?.[0];
+                e.target.value = "";
+                if (file) void importFlow(file);
+              }}
+            />
+            <div className="relative">
+              <Button
+                size="sm"
+                variant="ghost"
+                title="New flow from template"
+                disabled={!flowTemplates?.length}
+                onClick={() => setTemplatesOpen((prev) => !prev)}
+                className="h-7 w-7 rounded-lg p-0 text-slate-500"
+              >
+                <LayoutTemplate size={13} />
+              </Button>
+              {templatesOpen && (
+                <div className="absolute left-0 top-8 z-20 w-64 rounded-xl border border-slate-200 bg-white p-1 shadow-lg">
+                  {flowTemplates.map((template) => (
+                    <button
+                      key={template.key}
+                      onClick={() => {
+                        setTemplatesOpen(false);
+                        void createFlowFromTemplate(template.key);
+                      }}
+                      className="w-full rounded-lg px-3 py-2 text-left hover:bg-slate-50"
+                    >
+                      <p className="text-[12px] font-medium text-slate-800">
+                        {template.name}
+                      </p>
+                      <p className="text-[10px] text-slate-400">
+                        {template.description}
+                      </p>
+                    </button>
+                  ))}
+                </div>
+              )}
+            </div>
+            <Button
+              size="sm"
+              variant="ghost"
+              title="Import flow bundle"
+              onClick={() => importInputRef.current?.click()}
+              className="h-7 w-7 rounded-lg p-0 text-slate-500"
+            >
+              <Upload size={13} />
+            </Button>
+            <Button
+              size="sm"
+              variant="ghost"
+              title="Export selected flow"
+              disabled={!activeFlowId}
+              onClick={() => void exportFlow()}
+              className="h-7 w-7 rounded-lg p-0 text-slate-500"
+            >
+              <Download size={13} />
+            </Button>
+            <Button
+              size="sm"
+              onClick={createFlow}
+              className="h-7 rounded-lg bg-blue-500 px-2.5 text-[11px] text-white hover:bg-blue-600"
+            >
+              <Plus size={12} className="mr-1" /> New
+            </Button>
+          </div>
         </div>
         <ScrollArea className="h-full">
           <div className="space-y-1 p-2">
@@ -3559,6 +4892,8 @@ export default function FlowsView({
           setFlowAiTool={setFlowAiTool}
           flowAiToolDescription={flowAiToolDescription}
           setFlowAiToolDescription={setFlowAiToolDescription}
+          flowMaxSteps={flowMaxSteps}
+          setFlowMaxSteps={setFlowMaxSteps}
         />
         <div
           className="relative min-h-0"
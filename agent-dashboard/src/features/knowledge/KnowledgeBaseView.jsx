@@ -21,6 +21,7 @@ export default function KnowledgeBaseView({ apiFetch, token }) {
   const [selectedArticleId, setSelectedArticleId] = useState("");
   const [editorTitle, setEditorTitle] = useState("");
   const [editorMarkdown, setEditorMarkdown] = useState("");
+  const [editorLanguage, setEditorLanguage] = useState("en");
 
   const [newTagName, setNewTagName] = useState("");
   const [newTagColor, setNewTagColor] = useState("#3b82f6");
@@ -82,6 +83,7 @@ export default function KnowledgeBaseView({ apiFetch, token }) {
       setSelectedCollectionId(article.collectionId || "");
       setEditorTitle(article.title || "");
       setEditorMarkdown(article.markdown || "");
+      setEditorLanguage(article.language || "en");
       setArticles((prev) => prev.map((item) => (item.id === article.id ? article : item)));
     } catch (err) {
       setError(err.message);
@@ -122,6 +124,7 @@ export default function KnowledgeBaseView({ apiFetch, token }) {
           collectionId: selectedCollectionId,
           title: editorTitle.trim(),
           markdown: editorMarkdown,
+          language: editorLanguage,
           status: "draft",
         }),
       });
@@ -147,6 +150,7 @@ export default function KnowledgeBaseView({ apiFetch, token }) {
           collectionId: selectedCollectionId,
           title: editorTitle.trim(),
           markdown: editorMarkdown,
+          language: editorLanguage,
         }),
       });
       const article = res.article;
@@ -206,6 +210,7 @@ export default function KnowledgeBaseView({ apiFetch, token }) {
       setSelectedArticleId("");
       setEditorTitle("");
       setEditorMarkdown("");
+      setEditorLanguage("en");
     } catch (err) {
       setError(err.message);
     } finally {
@@ -364,6 +369,11 @@ export default function KnowledgeBaseView({ apiFetch, token }) {
 
           <div className="space-y-2">
             <Input value={editorTitle} onChange={(e) => setEditorTitle(e.target.value)} placeholder="Article title" />
+            <Input
+              value={editorLanguage}
+              onChange={(e) => setEditorLanguage(e.target.value)}
+              placeholder="Article language (e.g. en, pt-BR)"
+            />
             <select
               value={selectedCollectionId}
               onChange={(e) => setSelectedCollectionId(e.target.value)}
@@ -0,0 +1,7 @@
+fn main() {
+    // Use the vendored protoc so contributors and CI don't need one on PATH.
+    let protoc = protoc_bin_vendored::protoc_bin_path().expect("vendored protoc not available");
+    std::env::set_var("PROTOC", protoc);
+    tonic_prost_build::compile_protos("proto/chat.proto")
+        .expect("failed to compile proto/chat.proto");
+}
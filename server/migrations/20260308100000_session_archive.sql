@@ -0,0 +1,8 @@
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS archived_at TEXT;
+
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS deleted_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_sessions_visibility
+ON sessions (tenant_id, archived_at, deleted_at);
@@ -0,0 +1,19 @@
+CREATE TABLE
+    IF NOT EXISTS tenant_retention_policies (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        message_retention_days INTEGER NOT NULL DEFAULT 0,
+        note_retention_days INTEGER NOT NULL DEFAULT 0,
+        media_retention_days INTEGER NOT NULL DEFAULT 0,
+        mode TEXT NOT NULL DEFAULT 'anonymize',
+        last_run_at TEXT,
+        updated_at TEXT NOT NULL
+    );
+
+ALTER TABLE chat_messages
+ADD COLUMN IF NOT EXISTS redacted_at TEXT;
+
+ALTER TABLE conversation_notes
+ADD COLUMN IF NOT EXISTS redacted_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_chat_messages_session_created
+ON chat_messages (session_id, created_at);
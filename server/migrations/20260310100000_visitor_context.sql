@@ -0,0 +1,2 @@
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS visitor_context TEXT NOT NULL DEFAULT '{}';
@@ -0,0 +1,35 @@
+CREATE TABLE
+    IF NOT EXISTS campaigns (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        name TEXT NOT NULL,
+        enabled BOOLEAN NOT NULL DEFAULT TRUE,
+        message TEXT NOT NULL,
+        rules TEXT NOT NULL DEFAULT '[]',
+        logic_operator TEXT NOT NULL DEFAULT 'and',
+        delay_seconds INTEGER NOT NULL DEFAULT 0,
+        max_per_visitor INTEGER NOT NULL DEFAULT 1,
+        cap_window_hours INTEGER NOT NULL DEFAULT 0,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_campaigns_tenant ON campaigns (tenant_id, enabled);
+
+CREATE TABLE
+    IF NOT EXISTS campaign_deliveries (
+        id TEXT PRIMARY KEY,
+        campaign_id TEXT NOT NULL REFERENCES campaigns (id) ON DELETE CASCADE,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        visitor_id TEXT NOT NULL DEFAULT '',
+        delivered_at TEXT NOT NULL,
+        converted_at TEXT,
+        UNIQUE (campaign_id, session_id)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_campaign_deliveries_visitor
+ON campaign_deliveries (campaign_id, visitor_id, delivered_at);
+
+CREATE INDEX IF NOT EXISTS idx_campaign_deliveries_session
+ON campaign_deliveries (session_id);
@@ -0,0 +1,4 @@
+ALTER TABLE contacts
+ADD COLUMN IF NOT EXISTS country TEXT NOT NULL DEFAULT '',
+ADD COLUMN IF NOT EXISTS city TEXT NOT NULL DEFAULT '',
+ADD COLUMN IF NOT EXISTS timezone TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,3 @@
+ALTER TABLE contacts
+ADD COLUMN IF NOT EXISTS screen_size TEXT NOT NULL DEFAULT '',
+ADD COLUMN IF NOT EXISTS language TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,15 @@
+CREATE TABLE
+    IF NOT EXISTS session_events (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        kind TEXT NOT NULL,
+        actor_type TEXT NOT NULL DEFAULT 'system',
+        actor_id TEXT,
+        actor_name TEXT NOT NULL DEFAULT '',
+        data TEXT NOT NULL DEFAULT '{}',
+        message_id TEXT,
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_session_events_session ON session_events (session_id, created_at);
@@ -0,0 +1,30 @@
+CREATE TABLE
+    IF NOT EXISTS translation_memory (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        source_lang TEXT NOT NULL DEFAULT '',
+        target_lang TEXT NOT NULL,
+        source_hash TEXT NOT NULL,
+        source_text TEXT NOT NULL,
+        translated_text TEXT NOT NULL,
+        hits INTEGER NOT NULL DEFAULT 0,
+        created_at TEXT NOT NULL,
+        last_used_at TEXT NOT NULL,
+        UNIQUE (tenant_id, source_lang, target_lang, source_hash)
+    );
+
+CREATE TABLE
+    IF NOT EXISTS translation_glossary (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        source_lang TEXT NOT NULL DEFAULT '',
+        target_lang TEXT NOT NULL,
+        term TEXT NOT NULL,
+        translation TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        UNIQUE (tenant_id, source_lang, target_lang, term)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_translation_glossary_pair
+ON translation_glossary (tenant_id, target_lang);
@@ -0,0 +1,9 @@
+-- Feature flag overrides. An empty tenant_id holds the global value.
+CREATE TABLE
+    IF NOT EXISTS feature_flags (
+        tenant_id TEXT NOT NULL DEFAULT '',
+        flag TEXT NOT NULL,
+        enabled BOOLEAN NOT NULL,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (tenant_id, flag)
+    );
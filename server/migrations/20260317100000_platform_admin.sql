@@ -0,0 +1,41 @@
+-- Platform admin: tenant suspension, impersonation tokens and audit trail.
+ALTER TABLE tenants
+ADD COLUMN IF NOT EXISTS suspended_at TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE tenants
+ADD COLUMN IF NOT EXISTS suspended_reason TEXT NOT NULL DEFAULT '';
+
+-- Set on tokens issued to a platform admin acting as a workspace member.
+ALTER TABLE auth_tokens
+ADD COLUMN IF NOT EXISTS impersonated_by TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE auth_tokens
+ADD COLUMN IF NOT EXISTS expires_at TEXT NOT NULL DEFAULT '';
+
+CREATE TABLE
+    IF NOT EXISTS admin_audit_log (
+        id TEXT PRIMARY KEY,
+        actor TEXT NOT NULL,
+        action TEXT NOT NULL,
+        tenant_id TEXT NOT NULL DEFAULT '',
+        detail TEXT NOT NULL DEFAULT '{}',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_admin_audit_log_created ON admin_audit_log (created_at);
+
+-- Outcome of every outbound delivery (WhatsApp sends, flow webhooks, AI replies).
+CREATE TABLE
+    IF NOT EXISTS delivery_attempts (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL DEFAULT '',
+        session_id TEXT NOT NULL DEFAULT '',
+        channel TEXT NOT NULL,
+        kind TEXT NOT NULL,
+        ok BOOLEAN NOT NULL,
+        error TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_delivery_attempts_created ON delivery_attempts (created_at);
+CREATE INDEX IF NOT EXISTS idx_delivery_attempts_tenant ON delivery_attempts (tenant_id, created_at);
@@ -0,0 +1,30 @@
+-- Subscription state per workspace, kept in sync by Stripe webhooks.
+CREATE TABLE
+    IF NOT EXISTS tenant_subscriptions (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        plan TEXT NOT NULL DEFAULT 'free',
+        status TEXT NOT NULL DEFAULT 'active',
+        stripe_customer_id TEXT NOT NULL DEFAULT '',
+        stripe_subscription_id TEXT NOT NULL DEFAULT '',
+        current_period_end TEXT NOT NULL DEFAULT '',
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_tenant_subscriptions_customer ON tenant_subscriptions (stripe_customer_id);
+
+-- Metered usage that cannot be counted from existing rows. period is YYYY-MM (UTC).
+CREATE TABLE
+    IF NOT EXISTS tenant_usage (
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        period TEXT NOT NULL,
+        ai_calls BIGINT NOT NULL DEFAULT 0,
+        PRIMARY KEY (tenant_id, period)
+    );
+
+-- Stripe retries deliveries; remember handled event ids.
+CREATE TABLE
+    IF NOT EXISTS stripe_events (
+        id TEXT PRIMARY KEY,
+        event_type TEXT NOT NULL,
+        received_at TEXT NOT NULL
+    );
@@ -0,0 +1,19 @@
+-- Caps on concurrent open handovers and what happens to the ones over capacity.
+CREATE TABLE
+    IF NOT EXISTS tenant_queue_settings (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        max_per_agent INTEGER NOT NULL DEFAULT 0,
+        team_limits TEXT NOT NULL DEFAULT '{}',
+        overflow_behavior TEXT NOT NULL DEFAULT 'keep_bot',
+        backup_team_id TEXT,
+        overflow_message TEXT NOT NULL DEFAULT '',
+        updated_at TEXT NOT NULL
+    );
+
+-- Set while a handover waits for agent capacity.
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS queued_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_sessions_queued ON sessions (tenant_id, queued_at)
+WHERE
+    queued_at IS NOT NULL;
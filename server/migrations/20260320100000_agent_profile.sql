@@ -0,0 +1,6 @@
+-- Job title and reply signature shown on agent profiles.
+ALTER TABLE agents
+ADD COLUMN IF NOT EXISTS title TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE agents
+ADD COLUMN IF NOT EXISTS signature TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,3 @@
+-- Per-channel bot switch; NULL follows tenant_settings.bot_enabled_by_default.
+ALTER TABLE channels
+ADD COLUMN IF NOT EXISTS bot_enabled BOOLEAN;
@@ -0,0 +1,3 @@
+-- Weekly windows (JSON) during which the bot stays silent on a channel.
+ALTER TABLE channels
+ADD COLUMN IF NOT EXISTS bot_quiet_hours TEXT NOT NULL DEFAULT '{}';
@@ -0,0 +1,3 @@
+-- Who set the conversation priority: '' (default), 'ai' or 'agent'.
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS priority_source TEXT NOT NULL DEFAULT '';
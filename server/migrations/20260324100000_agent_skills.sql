@@ -0,0 +1,19 @@
+-- Routing skills (e.g. language:es, product:billing) with a 1-5 proficiency.
+CREATE TABLE
+    IF NOT EXISTS agent_skills (
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        skill TEXT NOT NULL,
+        proficiency INTEGER NOT NULL DEFAULT 3,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (agent_id, skill)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_agent_skills_tenant_skill ON agent_skills (tenant_id, skill);
+
+-- Skills the conversation needs from its assignee (JSON array).
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS required_skills TEXT NOT NULL DEFAULT '[]';
+
+ALTER TABLE tenant_queue_settings
+ADD COLUMN IF NOT EXISTS auto_assign BOOLEAN NOT NULL DEFAULT FALSE;
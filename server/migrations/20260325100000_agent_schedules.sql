@@ -0,0 +1,36 @@
+-- Weekly working hours per agent (JSON windows); no row means always scheduled.
+CREATE TABLE
+    IF NOT EXISTS agent_schedules (
+        agent_id TEXT PRIMARY KEY REFERENCES agents (id) ON DELETE CASCADE,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        utc_offset_minutes INTEGER NOT NULL DEFAULT 0,
+        windows TEXT NOT NULL DEFAULT '[]',
+        updated_at TEXT NOT NULL
+    );
+
+CREATE TABLE
+    IF NOT EXISTS agent_time_off (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        starts_at TEXT NOT NULL,
+        ends_at TEXT NOT NULL,
+        reason TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_agent_time_off_agent ON agent_time_off (agent_id, ends_at);
+
+-- Periods an agent had the dashboard connected; last_seen_at closes periods
+-- left open by a server restart.
+CREATE TABLE
+    IF NOT EXISTS agent_presence_sessions (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        started_at TEXT NOT NULL,
+        last_seen_at TEXT NOT NULL,
+        ended_at TEXT
+    );
+
+CREATE INDEX IF NOT EXISTS idx_agent_presence_tenant ON agent_presence_sessions (tenant_id, started_at);
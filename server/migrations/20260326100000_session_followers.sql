@@ -0,0 +1,11 @@
+-- Agents following a conversation to get its notifications without being assigned.
+CREATE TABLE
+    IF NOT EXISTS session_followers (
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        created_at TEXT NOT NULL,
+        PRIMARY KEY (session_id, agent_id)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_session_followers_agent ON session_followers (agent_id);
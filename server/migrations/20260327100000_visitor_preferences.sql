@@ -0,0 +1,11 @@
+-- Widget preferences per visitor, shared by every session of that visitor.
+CREATE TABLE
+    IF NOT EXISTS visitor_preferences (
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        visitor_id TEXT NOT NULL,
+        sound_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+        email_transcript BOOLEAN NOT NULL DEFAULT FALSE,
+        language TEXT NOT NULL DEFAULT '',
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (tenant_id, visitor_id)
+    );
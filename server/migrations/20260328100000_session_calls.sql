@@ -0,0 +1,19 @@
+-- Video/voice meeting links escalated from a conversation, for reporting.
+CREATE TABLE
+    IF NOT EXISTS session_calls (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        provider TEXT NOT NULL,
+        kind TEXT NOT NULL DEFAULT 'video',
+        room TEXT NOT NULL,
+        join_url TEXT NOT NULL,
+        started_by_agent_id TEXT,
+        started_at TEXT NOT NULL,
+        ended_at TEXT,
+        duration_sec BIGINT
+    );
+
+CREATE INDEX IF NOT EXISTS idx_session_calls_session ON session_calls (session_id);
+
+CREATE INDEX IF NOT EXISTS idx_session_calls_tenant_started ON session_calls (tenant_id, started_at);
@@ -0,0 +1,10 @@
+-- Malware scan verdicts for files under the media storage dir. Files without
+-- a clean verdict are scanned again before they are served.
+CREATE TABLE
+    IF NOT EXISTS media_scans (
+        file_name TEXT PRIMARY KEY,
+        tenant_id TEXT,
+        status TEXT NOT NULL,
+        signature TEXT NOT NULL DEFAULT '',
+        scanned_at TEXT NOT NULL
+    );
@@ -0,0 +1,53 @@
+-- Ownership and access level of files under the media storage dir. Anything
+-- that is not `public` is only served through a signed, expiring URL.
+CREATE TABLE
+    IF NOT EXISTS media_files (
+        file_name TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL,
+        visibility TEXT NOT NULL DEFAULT 'private',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_media_files_tenant ON media_files (tenant_id);
+
+-- Per-workspace URL signing key; created on first use. A TTL of 0 falls back
+-- to the server default (MEDIA_URL_TTL_SECS).
+CREATE TABLE
+    IF NOT EXISTS tenant_media_keys (
+        tenant_id TEXT PRIMARY KEY,
+        secret TEXT NOT NULL,
+        url_ttl_secs INTEGER NOT NULL DEFAULT 0,
+        updated_at TEXT NOT NULL
+    );
+
+-- Nonces of one-time download links that have been redeemed.
+CREATE TABLE
+    IF NOT EXISTS media_download_tokens (
+        nonce TEXT PRIMARY KEY,
+        file_name TEXT NOT NULL,
+        used_at TEXT NOT NULL
+    );
+
+-- Avatars are rendered in places that cannot carry a signature.
+INSERT INTO media_files (file_name, tenant_id, visibility, created_at)
+SELECT DISTINCT ON (file_name) file_name, tenant_id, 'public', to_char(now() AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"')
+FROM (
+        SELECT substring(avatar_url FROM 12) AS file_name, tenant_id
+        FROM agents WHERE avatar_url LIKE '/api/media/%'
+        UNION ALL
+        SELECT substring(bot_avatar_url FROM 12), tenant_id
+        FROM tenant_settings WHERE bot_avatar_url LIKE '/api/media/%'
+    ) avatars
+WHERE tenant_id IS NOT NULL
+ON CONFLICT (file_name) DO NOTHING;
+
+INSERT INTO media_files (file_name, tenant_id, visibility, created_at)
+SELECT DISTINCT ON (file_name) file_name, tenant_id, 'private', to_char(now() AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"')
+FROM (
+        SELECT substring(m.widget::jsonb->>'url' FROM 12) AS file_name, s.tenant_id
+        FROM chat_messages m
+        JOIN sessions s ON s.id = m.session_id
+        WHERE m.widget IS NOT NULL AND (m.widget::jsonb->>'url') LIKE '/api/media/%'
+    ) attachments
+WHERE tenant_id IS NOT NULL
+ON CONFLICT (file_name) DO NOTHING;
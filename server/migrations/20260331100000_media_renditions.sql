@@ -0,0 +1,4 @@
+-- Thumbnail and web rendition generated for an image original, as JSON
+-- `{"thumbnail": file, "preview": file, "width": n, "height": n}`.
+ALTER TABLE media_files
+ADD COLUMN IF NOT EXISTS renditions TEXT;
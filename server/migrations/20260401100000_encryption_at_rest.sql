@@ -0,0 +1,27 @@
+-- Optional envelope encryption of message text and stored files. Each
+-- workspace has data keys wrapped by the local master key or a KMS; message
+-- text carries the id of the key that sealed it (`enc:v1:{key_id}:...`).
+CREATE TABLE
+    IF NOT EXISTS tenant_encryption (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        enabled BOOLEAN NOT NULL DEFAULT false,
+        active_key_id TEXT,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE TABLE
+    IF NOT EXISTS tenant_data_keys (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        provider TEXT NOT NULL,
+        wrapped_key TEXT NOT NULL,
+        status TEXT NOT NULL DEFAULT 'active',
+        created_at TEXT NOT NULL,
+        retired_at TEXT
+    );
+
+CREATE INDEX IF NOT EXISTS idx_tenant_data_keys_tenant ON tenant_data_keys (tenant_id, created_at);
+
+-- Key that sealed the file on disk; NULL for plaintext files.
+ALTER TABLE media_files
+ADD COLUMN IF NOT EXISTS encryption_key_id TEXT;
@@ -0,0 +1,13 @@
+-- Per-workspace PII masking of visitor messages. Built-in detectors can be
+-- switched off individually; custom_patterns is a JSON array of
+-- `{"name": "...", "pattern": "<regex>"}`.
+CREATE TABLE
+    IF NOT EXISTS tenant_pii_settings (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        enabled BOOLEAN NOT NULL DEFAULT false,
+        detect_cards BOOLEAN NOT NULL DEFAULT true,
+        detect_national_ids BOOLEAN NOT NULL DEFAULT true,
+        detect_passwords BOOLEAN NOT NULL DEFAULT true,
+        custom_patterns TEXT NOT NULL DEFAULT '[]',
+        updated_at TEXT NOT NULL
+    );
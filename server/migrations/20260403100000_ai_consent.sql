@@ -0,0 +1,11 @@
+-- Visitor consent for AI processing. A session with no decision has a NULL
+-- ai_consent; workspaces that require consent keep those sessions away from
+-- the bot. ai_consent_text is the prompt wording the visitor answered.
+ALTER TABLE tenant_settings
+ADD COLUMN IF NOT EXISTS ai_consent_required BOOLEAN NOT NULL DEFAULT false,
+ADD COLUMN IF NOT EXISTS ai_consent_text TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS ai_consent TEXT,
+ADD COLUMN IF NOT EXISTS ai_consent_at TEXT,
+ADD COLUMN IF NOT EXISTS ai_consent_text TEXT;
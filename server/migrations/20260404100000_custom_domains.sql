@@ -0,0 +1,18 @@
+-- Customer hostnames for the widget, media and webhooks. Ownership is proven
+-- with a TXT record at _chat-verify.<hostname>; routing with a CNAME to the
+-- platform host.
+CREATE TABLE
+    IF NOT EXISTS tenant_domains (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        hostname TEXT NOT NULL UNIQUE,
+        status TEXT NOT NULL DEFAULT 'pending',
+        cert_status TEXT NOT NULL DEFAULT 'none',
+        verification_token TEXT NOT NULL,
+        verified_at TEXT,
+        last_checked_at TEXT,
+        last_error TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_tenant_domains_tenant ON tenant_domains (tenant_id);
@@ -0,0 +1,4 @@
+-- IANA time zone used when a visitor's own zone is unknown (flow business
+-- hours and {{now}} variables).
+ALTER TABLE tenant_settings
+ADD COLUMN IF NOT EXISTS default_timezone TEXT NOT NULL DEFAULT 'UTC';
@@ -0,0 +1,28 @@
+-- Callbacks visitors book against agents' weekly schedules. The reminder
+-- sweeper notifies the assigned agent once scheduled_for passes.
+CREATE TABLE
+    IF NOT EXISTS callback_requests (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        contact_id TEXT,
+        agent_id TEXT REFERENCES agents (id) ON DELETE SET NULL,
+        scheduled_for TEXT NOT NULL,
+        duration_minutes INTEGER NOT NULL DEFAULT 30,
+        timezone TEXT NOT NULL DEFAULT 'UTC',
+        phone TEXT NOT NULL DEFAULT '',
+        note TEXT NOT NULL DEFAULT '',
+        status TEXT NOT NULL DEFAULT 'scheduled',
+        outcome TEXT NOT NULL DEFAULT '',
+        reminded_at TEXT,
+        completed_at TEXT,
+        completed_by TEXT,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_callback_requests_tenant ON callback_requests (tenant_id, scheduled_for);
+
+CREATE INDEX IF NOT EXISTS idx_callback_requests_due ON callback_requests (status, scheduled_for);
+
+CREATE INDEX IF NOT EXISTS idx_callback_requests_agent ON callback_requests (agent_id, scheduled_for);
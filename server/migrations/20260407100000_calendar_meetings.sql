@@ -0,0 +1,45 @@
+-- Agents' Google/Microsoft calendars, connected through OAuth. Busy time
+-- from them limits which meeting slots a flow offers.
+CREATE TABLE
+    IF NOT EXISTS agent_calendar_connections (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        provider TEXT NOT NULL,
+        account_email TEXT NOT NULL DEFAULT '',
+        calendar_id TEXT NOT NULL DEFAULT 'primary',
+        access_token TEXT NOT NULL,
+        refresh_token TEXT NOT NULL DEFAULT '',
+        token_expires_at TEXT NOT NULL,
+        status TEXT NOT NULL DEFAULT 'active',
+        last_error TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        UNIQUE (agent_id, provider)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_agent_calendar_connections_tenant ON agent_calendar_connections (tenant_id);
+
+CREATE TABLE
+    IF NOT EXISTS meetings (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        agent_id TEXT REFERENCES agents (id) ON DELETE SET NULL,
+        connection_id TEXT REFERENCES agent_calendar_connections (id) ON DELETE SET NULL,
+        provider TEXT NOT NULL,
+        external_event_id TEXT NOT NULL DEFAULT '',
+        title TEXT NOT NULL DEFAULT '',
+        starts_at TEXT NOT NULL,
+        ends_at TEXT NOT NULL,
+        timezone TEXT NOT NULL DEFAULT 'UTC',
+        visitor_email TEXT NOT NULL,
+        visitor_name TEXT NOT NULL DEFAULT '',
+        status TEXT NOT NULL DEFAULT 'scheduled',
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_meetings_tenant ON meetings (tenant_id, starts_at);
+
+CREATE INDEX IF NOT EXISTS idx_meetings_session ON meetings (session_id);
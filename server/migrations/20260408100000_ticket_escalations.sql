@@ -0,0 +1,38 @@
+-- Zendesk/Jira accounts a workspace escalates conversations to.
+CREATE TABLE
+    IF NOT EXISTS ticket_integrations (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        provider TEXT NOT NULL,
+        base_url TEXT NOT NULL,
+        account_email TEXT NOT NULL,
+        api_token TEXT NOT NULL,
+        project_key TEXT NOT NULL DEFAULT '',
+        issue_type TEXT NOT NULL DEFAULT 'Task',
+        enabled BOOLEAN NOT NULL DEFAULT true,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        UNIQUE (tenant_id, provider)
+    );
+
+-- External tickets opened from a session; status is polled back.
+CREATE TABLE
+    IF NOT EXISTS session_tickets (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        provider TEXT NOT NULL,
+        external_id TEXT NOT NULL,
+        url TEXT NOT NULL DEFAULT '',
+        subject TEXT NOT NULL DEFAULT '',
+        status TEXT NOT NULL DEFAULT '',
+        resolved BOOLEAN NOT NULL DEFAULT false,
+        created_by TEXT REFERENCES agents (id) ON DELETE SET NULL,
+        synced_at TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_session_tickets_session ON session_tickets (session_id);
+
+CREATE INDEX IF NOT EXISTS idx_session_tickets_open ON session_tickets (resolved, synced_at);
@@ -0,0 +1,47 @@
+-- HubSpot/Salesforce accounts whose contacts are kept in sync.
+CREATE TABLE
+    IF NOT EXISTS crm_integrations (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        provider TEXT NOT NULL,
+        instance_url TEXT NOT NULL DEFAULT '',
+        api_token TEXT NOT NULL DEFAULT '',
+        client_id TEXT NOT NULL DEFAULT '',
+        client_secret TEXT NOT NULL DEFAULT '',
+        field_mappings TEXT NOT NULL DEFAULT '[]',
+        push_contacts BOOLEAN NOT NULL DEFAULT true,
+        push_summaries BOOLEAN NOT NULL DEFAULT true,
+        pull_on_open BOOLEAN NOT NULL DEFAULT true,
+        enabled BOOLEAN NOT NULL DEFAULT true,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        UNIQUE (tenant_id, provider)
+    );
+
+-- Which CRM record a contact maps to. An empty external_id with pushed_at
+-- set marks a failed automatic push; a manual sync retries it.
+CREATE TABLE
+    IF NOT EXISTS crm_contact_links (
+        contact_id TEXT NOT NULL REFERENCES contacts (id) ON DELETE CASCADE,
+        provider TEXT NOT NULL,
+        external_id TEXT NOT NULL DEFAULT '',
+        pushed_at TEXT NOT NULL DEFAULT '',
+        pulled_at TEXT NOT NULL DEFAULT '',
+        PRIMARY KEY (contact_id, provider)
+    );
+
+CREATE TABLE
+    IF NOT EXISTS crm_sync_log (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        provider TEXT NOT NULL,
+        direction TEXT NOT NULL,
+        action TEXT NOT NULL,
+        contact_id TEXT,
+        session_id TEXT,
+        status TEXT NOT NULL,
+        detail TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_crm_sync_log_tenant ON crm_sync_log (tenant_id, created_at DESC);
@@ -0,0 +1,17 @@
+-- Credentials for outgoing connectors (Slack, Google Sheets, Zapier) used
+-- by flow action nodes. `credentials` is a JSON object sealed with the
+-- workspace's data key.
+CREATE TABLE
+    IF NOT EXISTS connector_accounts (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        connector TEXT NOT NULL,
+        name TEXT NOT NULL,
+        credentials TEXT NOT NULL,
+        configured_fields TEXT NOT NULL DEFAULT '[]',
+        created_by TEXT REFERENCES agents (id) ON DELETE SET NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_connector_accounts_tenant ON connector_accounts (tenant_id);
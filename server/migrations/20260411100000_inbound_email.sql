@@ -0,0 +1,13 @@
+-- Message-ID headers of inbound emails, so replies (In-Reply-To /
+-- References) land in the session the thread started.
+CREATE TABLE
+    IF NOT EXISTS email_messages (
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        message_id TEXT NOT NULL,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        subject TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL,
+        PRIMARY KEY (tenant_id, message_id)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_email_messages_session ON email_messages (session_id);
@@ -0,0 +1,10 @@
+-- Unsent composer text per agent and session, so a reply started on one
+-- device can be finished on another. Text is sealed like message text.
+CREATE TABLE
+    IF NOT EXISTS agent_drafts (
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        text TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (agent_id, session_id)
+    );
@@ -0,0 +1,17 @@
+-- Agent reply templates with {{variables}} rendered at insertion time.
+-- team_id NULL shares a template with the whole workspace.
+CREATE TABLE
+    IF NOT EXISTS reply_templates (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        title TEXT NOT NULL,
+        body TEXT NOT NULL,
+        team_id TEXT REFERENCES teams (id) ON DELETE CASCADE,
+        created_by TEXT REFERENCES agents (id) ON DELETE SET NULL,
+        usage_count BIGINT NOT NULL DEFAULT 0,
+        last_used_at TEXT,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_reply_templates_tenant ON reply_templates (tenant_id);
@@ -0,0 +1,22 @@
+-- Supervisor QA notes on message ranges. agent_id is the agent being
+-- reviewed; scores feed the weekly QA trend report.
+CREATE TABLE
+    IF NOT EXISTS qa_highlights (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        agent_id TEXT REFERENCES agents (id) ON DELETE SET NULL,
+        reviewer_id TEXT REFERENCES agents (id) ON DELETE SET NULL,
+        reviewer_name TEXT NOT NULL DEFAULT '',
+        start_message_id TEXT NOT NULL,
+        end_message_id TEXT NOT NULL,
+        criterion TEXT NOT NULL DEFAULT 'overall',
+        score INTEGER NOT NULL,
+        comment TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_qa_highlights_session ON qa_highlights (session_id);
+
+CREATE INDEX IF NOT EXISTS idx_qa_highlights_agent ON qa_highlights (tenant_id, agent_id, created_at);
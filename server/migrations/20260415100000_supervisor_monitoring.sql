@@ -0,0 +1,29 @@
+-- Supervisor live monitoring: whispers only the handling agent sees (text
+-- sealed like message text) and an audit trail of supervisor actions.
+CREATE TABLE
+    IF NOT EXISTS supervisor_whispers (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        supervisor_id TEXT NOT NULL,
+        supervisor_name TEXT NOT NULL DEFAULT '',
+        agent_id TEXT NOT NULL,
+        text TEXT NOT NULL,
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_supervisor_whispers_session ON supervisor_whispers (session_id, created_at);
+
+CREATE TABLE
+    IF NOT EXISTS supervisor_audit_log (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        supervisor_id TEXT REFERENCES agents (id) ON DELETE SET NULL,
+        supervisor_name TEXT NOT NULL DEFAULT '',
+        action TEXT NOT NULL,
+        agent_id TEXT,
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_supervisor_audit_log_tenant ON supervisor_audit_log (tenant_id, created_at);
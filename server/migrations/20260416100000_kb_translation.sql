@@ -0,0 +1,15 @@
+-- Language KB articles are written in, so AI answers can translate grounding
+-- for visitors who speak another language.
+ALTER TABLE kb_articles
+ADD COLUMN IF NOT EXISTS language TEXT NOT NULL DEFAULT 'en';
+
+-- Translated chunk text per target language. Rows go away with the chunk
+-- when an article is reindexed, so edits never serve a stale translation.
+CREATE TABLE
+    IF NOT EXISTS kb_chunk_translations (
+        chunk_id TEXT NOT NULL REFERENCES kb_chunks (id) ON DELETE CASCADE,
+        target_lang TEXT NOT NULL,
+        content_text TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        PRIMARY KEY (chunk_id, target_lang)
+    );
@@ -0,0 +1,18 @@
+-- WhatsApp Flows (native forms) sent by flow nodes. The flow token Meta
+-- echoes back on submission ties the answers to the paused chat flow.
+CREATE TABLE
+    IF NOT EXISTS whatsapp_flow_forms (
+        flow_token TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        channel_id TEXT NOT NULL,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        chat_flow_id TEXT NOT NULL,
+        node_id TEXT NOT NULL,
+        meta_flow_id TEXT NOT NULL,
+        screen TEXT NOT NULL DEFAULT '',
+        status TEXT NOT NULL DEFAULT 'sent',
+        created_at TEXT NOT NULL,
+        completed_at TEXT
+    );
+
+CREATE INDEX IF NOT EXISTS idx_whatsapp_flow_forms_session ON whatsapp_flow_forms (session_id, created_at);
@@ -0,0 +1,21 @@
+-- Test mode: outbound messages on the channel are captured instead of sent
+-- to the provider, and its conversations are flagged as test sessions.
+ALTER TABLE channels
+ADD COLUMN IF NOT EXISTS test_mode BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS is_test BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE
+    IF NOT EXISTS channel_test_captures (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        channel_id TEXT NOT NULL REFERENCES channels (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL DEFAULT '',
+        kind TEXT NOT NULL,
+        payload TEXT NOT NULL DEFAULT '{}',
+        rendered_text TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_channel_test_captures_channel ON channel_test_captures (channel_id, created_at);
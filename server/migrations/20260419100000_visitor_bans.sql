@@ -0,0 +1,14 @@
+-- Workspace ban list: a banned visitor identity cannot open conversations
+-- or send messages on any channel until the ban is lifted or expires.
+CREATE TABLE
+    IF NOT EXISTS visitor_bans (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        kind TEXT NOT NULL,
+        value TEXT NOT NULL,
+        reason TEXT NOT NULL DEFAULT '',
+        expires_at TEXT NOT NULL DEFAULT '',
+        created_by TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL,
+        UNIQUE (tenant_id, kind, value)
+    );
@@ -0,0 +1,13 @@
+-- Per-workspace network rules for the web widget, checked when a visitor
+-- opens or joins a conversation. Lists are JSON arrays of IPs or CIDR
+-- ranges; blocked_countries holds ISO 3166-1 alpha-2 codes.
+CREATE TABLE
+    IF NOT EXISTS tenant_network_rules (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        enabled BOOLEAN NOT NULL DEFAULT false,
+        deny_list TEXT NOT NULL DEFAULT '[]',
+        allow_list TEXT NOT NULL DEFAULT '[]',
+        blocked_countries TEXT NOT NULL DEFAULT '[]',
+        denial_message TEXT NOT NULL DEFAULT '',
+        updated_at TEXT NOT NULL
+    );
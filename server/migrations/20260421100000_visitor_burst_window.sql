@@ -0,0 +1,4 @@
+-- Debounce window for rapid-fire visitor messages: fragments sent within it
+-- reach flows and AI as one turn. 0 processes every message on its own.
+ALTER TABLE tenant_settings
+ADD COLUMN IF NOT EXISTS visitor_burst_window_ms INTEGER NOT NULL DEFAULT 0;
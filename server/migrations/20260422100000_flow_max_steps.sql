@@ -0,0 +1,4 @@
+-- Most nodes a single flow run may execute before it is stopped.
+-- 0 falls back to the server default.
+ALTER TABLE flows
+ADD COLUMN IF NOT EXISTS max_steps INTEGER NOT NULL DEFAULT 0;
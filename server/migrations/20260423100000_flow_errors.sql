@@ -0,0 +1,16 @@
+-- Dead letters for flow nodes that failed without an `error` edge to
+-- recover through, kept for flow analytics.
+CREATE TABLE
+    IF NOT EXISTS flow_errors (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        flow_id TEXT NOT NULL,
+        session_id TEXT NOT NULL DEFAULT '',
+        node_id TEXT NOT NULL,
+        node_type TEXT NOT NULL DEFAULT '',
+        error TEXT NOT NULL DEFAULT '',
+        payload TEXT NOT NULL DEFAULT '{}',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_flow_errors_flow ON flow_errors (tenant_id, flow_id, created_at);
@@ -0,0 +1,7 @@
+-- Rolling summary of conversation history that has scrolled out of the
+-- AI context window, and how many messages it covers.
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS ai_memory TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS ai_memory_upto INTEGER NOT NULL DEFAULT 0;
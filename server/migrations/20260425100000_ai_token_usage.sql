@@ -0,0 +1,22 @@
+-- Prompt and completion tokens per AI call, for usage metering.
+CREATE TABLE
+    IF NOT EXISTS ai_token_usage (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL DEFAULT '',
+        kind TEXT NOT NULL,
+        model TEXT NOT NULL,
+        prompt_tokens BIGINT NOT NULL DEFAULT 0,
+        completion_tokens BIGINT NOT NULL DEFAULT 0,
+        estimated_prompt_tokens BIGINT NOT NULL DEFAULT 0,
+        trimmed_tokens BIGINT NOT NULL DEFAULT 0,
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_ai_token_usage_tenant_created ON ai_token_usage (tenant_id, created_at);
+
+ALTER TABLE tenant_usage
+ADD COLUMN IF NOT EXISTS ai_prompt_tokens BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE tenant_usage
+ADD COLUMN IF NOT EXISTS ai_completion_tokens BIGINT NOT NULL DEFAULT 0;
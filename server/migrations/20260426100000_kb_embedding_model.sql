@@ -0,0 +1,15 @@
+-- Embedders differ in dimension, so chunk vectors are no longer fixed at
+-- 3072 and each chunk records which embedder produced it. Search only
+-- compares vectors from the configured embedder; `server reembed`
+-- rebuilds the rest after a provider change.
+ALTER TABLE kb_chunks
+ALTER COLUMN embedding TYPE vector;
+
+ALTER TABLE kb_chunks
+ADD COLUMN IF NOT EXISTS embedding_model TEXT NOT NULL DEFAULT '';
+
+UPDATE kb_chunks
+SET embedding_model = 'openai:text-embedding-3-large'
+WHERE embedding_model = '' AND embedding IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_kb_chunks_embedding_model ON kb_chunks (embedding_model);
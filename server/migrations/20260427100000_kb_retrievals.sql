@@ -0,0 +1,29 @@
+-- Each KB lookup made for an AI answer, and whether anything matched it.
+CREATE TABLE
+    IF NOT EXISTS kb_queries (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL DEFAULT '',
+        query TEXT NOT NULL,
+        matched BOOLEAN NOT NULL DEFAULT false,
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_kb_queries_tenant_created ON kb_queries (tenant_id, created_at);
+
+-- Chunks a lookup returned to the model. Chunk ids change on reindex, so
+-- only the article is a foreign key.
+CREATE TABLE
+    IF NOT EXISTS kb_retrievals (
+        query_id TEXT NOT NULL REFERENCES kb_queries (id) ON DELETE CASCADE,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL DEFAULT '',
+        article_id TEXT NOT NULL REFERENCES kb_articles (id) ON DELETE CASCADE,
+        chunk_id TEXT NOT NULL,
+        rank INTEGER NOT NULL,
+        created_at TEXT NOT NULL,
+        PRIMARY KEY (query_id, chunk_id)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_kb_retrievals_tenant_created ON kb_retrievals (tenant_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_kb_retrievals_article ON kb_retrievals (article_id);
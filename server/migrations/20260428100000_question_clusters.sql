@@ -0,0 +1,18 @@
+-- Visitor questions the bot could not answer well (no KB match, or the
+-- chat escalated right after), grouped by embedding similarity. Rebuilt
+-- per workspace by the periodic clustering job.
+CREATE TABLE
+    IF NOT EXISTS question_clusters (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        representative TEXT NOT NULL,
+        size INTEGER NOT NULL DEFAULT 0,
+        escalated INTEGER NOT NULL DEFAULT 0,
+        low_confidence INTEGER NOT NULL DEFAULT 0,
+        examples TEXT NOT NULL DEFAULT '[]',
+        first_seen_at TEXT NOT NULL,
+        last_seen_at TEXT NOT NULL,
+        computed_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_question_clusters_tenant ON question_clusters (tenant_id, size DESC);
@@ -0,0 +1,8 @@
+-- AI replies below this confidence (0-1) are not sent; the bot asks a
+-- clarifying question or hands over instead. 0 turns the check off. Flow AI
+-- nodes can override both per node.
+ALTER TABLE tenant_settings
+ADD COLUMN IF NOT EXISTS ai_confidence_threshold DOUBLE PRECISION NOT NULL DEFAULT 0;
+
+ALTER TABLE tenant_settings
+ADD COLUMN IF NOT EXISTS low_confidence_action TEXT NOT NULL DEFAULT 'clarify';
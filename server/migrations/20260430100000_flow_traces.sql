@@ -0,0 +1,22 @@
+-- Decision trace of each flow run: every node reached with the variables on
+-- entry and the edge that led there, raw AI outputs and failures, so support
+-- can replay why the bot answered the way it did. Variables and data are
+-- sealed like message text. Pruned after 14 days.
+CREATE TABLE
+    IF NOT EXISTS flow_traces (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        flow_id TEXT NOT NULL,
+        run_id TEXT NOT NULL,
+        seq INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        node_id TEXT NOT NULL DEFAULT '',
+        node_type TEXT NOT NULL DEFAULT '',
+        via_edge_id TEXT,
+        variables TEXT,
+        data TEXT NOT NULL DEFAULT '{}',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_flow_traces_session ON flow_traces (session_id, created_at);
@@ -0,0 +1,18 @@
+-- Name and avatar belong to the user and are shared by their agents in every
+-- workspace.
+ALTER TABLE users
+ADD COLUMN IF NOT EXISTS avatar_url TEXT NOT NULL DEFAULT '';
+
+UPDATE users u
+SET
+    avatar_url = a.avatar_url
+FROM
+    (
+        SELECT DISTINCT ON (user_id) user_id, avatar_url
+        FROM agents
+        WHERE user_id IS NOT NULL AND avatar_url <> ''
+        ORDER BY user_id, id
+    ) a
+WHERE
+    a.user_id = u.id
+    AND u.avatar_url = '';
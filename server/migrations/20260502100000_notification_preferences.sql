@@ -0,0 +1,11 @@
+-- Per-agent opt-outs by notification kind and delivery channel. Only rows
+-- that differ from the defaults need to exist.
+CREATE TABLE
+    IF NOT EXISTS agent_notification_preferences (
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        kind TEXT NOT NULL,
+        channel TEXT NOT NULL,
+        enabled BOOLEAN NOT NULL,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (agent_id, kind, channel)
+    );
@@ -0,0 +1,13 @@
+-- Summary emails for workspace owners and admins. A row is created the
+-- first time an admin is considered for a digest; `frequency` is `daily`,
+-- `weekly` or `off` (set by the unsubscribe link).
+CREATE TABLE
+    IF NOT EXISTS digest_subscriptions (
+        agent_id TEXT PRIMARY KEY REFERENCES agents (id) ON DELETE CASCADE,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        frequency TEXT NOT NULL DEFAULT 'weekly',
+        unsubscribe_token TEXT NOT NULL UNIQUE,
+        last_sent_at TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
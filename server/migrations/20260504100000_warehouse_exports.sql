@@ -0,0 +1,29 @@
+-- Nightly export of sessions, messages, events and CSAT to a workspace's
+-- data warehouse. `credentials` is sealed with the workspace data key.
+CREATE TABLE
+    IF NOT EXISTS warehouse_exports (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        destination TEXT NOT NULL,
+        credentials TEXT NOT NULL,
+        configured_fields TEXT NOT NULL DEFAULT '[]',
+        enabled BOOLEAN NOT NULL DEFAULT true,
+        last_run_at TEXT NOT NULL DEFAULT '',
+        last_status TEXT NOT NULL DEFAULT '',
+        last_error TEXT NOT NULL DEFAULT '',
+        last_row_count BIGINT NOT NULL DEFAULT 0,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+-- Export position per table: the last exported (cursor_at, cursor_id).
+-- Keyed by schema version so a new version starts from the beginning.
+CREATE TABLE
+    IF NOT EXISTS warehouse_export_cursors (
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        table_name TEXT NOT NULL,
+        schema_version BIGINT NOT NULL,
+        cursor_at TEXT NOT NULL DEFAULT '',
+        cursor_id TEXT NOT NULL DEFAULT '',
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (tenant_id, table_name, schema_version)
+    );
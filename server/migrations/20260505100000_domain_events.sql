@@ -0,0 +1,38 @@
+-- Append-only log of domain state changes. Rows are never updated or
+-- deleted by the application; projections are derived from them and can be
+-- rebuilt with `chat-server replay-events`. The log starts with this
+-- migration: older history is not backfilled.
+CREATE TABLE
+    IF NOT EXISTS domain_events (
+        seq BIGSERIAL PRIMARY KEY,
+        id TEXT NOT NULL UNIQUE,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        aggregate_type TEXT NOT NULL,
+        aggregate_id TEXT NOT NULL,
+        kind TEXT NOT NULL,
+        data TEXT NOT NULL DEFAULT '{}',
+        occurred_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_domain_events_aggregate ON domain_events (aggregate_type, aggregate_id, seq);
+
+-- Last event folded into each projection.
+CREATE TABLE
+    IF NOT EXISTS domain_event_projections (
+        name TEXT PRIMARY KEY,
+        last_seq BIGINT NOT NULL DEFAULT 0,
+        updated_at TEXT NOT NULL
+    );
+
+-- Projection: per-day conversation and message counts (UTC days).
+CREATE TABLE
+    IF NOT EXISTS daily_session_stats (
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        day TEXT NOT NULL,
+        sessions_created BIGINT NOT NULL DEFAULT 0,
+        sessions_resolved BIGINT NOT NULL DEFAULT 0,
+        visitor_messages BIGINT NOT NULL DEFAULT 0,
+        agent_messages BIGINT NOT NULL DEFAULT 0,
+        bot_messages BIGINT NOT NULL DEFAULT 0,
+        PRIMARY KEY (tenant_id, day)
+    );
@@ -0,0 +1,31 @@
+-- Realtime events written in the same transaction as the change they
+-- describe, so a crash before the emit cannot lose them. The dispatcher
+-- emits each row to connected clients, then to the workspace's event
+-- webhook, and deletes it once both are done (or the webhook gave up).
+CREATE TABLE
+    IF NOT EXISTS event_outbox (
+        seq BIGSERIAL PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL DEFAULT '',
+        event TEXT NOT NULL,
+        payload TEXT NOT NULL DEFAULT '{}',
+        created_at TEXT NOT NULL,
+        emitted_at TEXT,
+        attempts BIGINT NOT NULL DEFAULT 0,
+        next_attempt_at TEXT NOT NULL DEFAULT '',
+        last_error TEXT NOT NULL DEFAULT ''
+    );
+
+CREATE INDEX IF NOT EXISTS idx_event_outbox_next_attempt ON event_outbox (next_attempt_at, seq);
+
+-- Where a workspace receives its events. The signing secret is sealed with
+-- the workspace data key.
+CREATE TABLE
+    IF NOT EXISTS event_webhooks (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        url TEXT NOT NULL,
+        secret TEXT NOT NULL,
+        enabled BOOLEAN NOT NULL DEFAULT TRUE,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
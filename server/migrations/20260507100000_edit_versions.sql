@@ -0,0 +1,11 @@
+-- Edit counters for optimistic concurrency: updates must name the version
+-- they were made against (If-Match) and bump it, so concurrent saves
+-- conflict instead of overwriting each other.
+ALTER TABLE flows
+ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 1;
+
+ALTER TABLE tenant_settings
+ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 1;
+
+ALTER TABLE channels
+ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 1;
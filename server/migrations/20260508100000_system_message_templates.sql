@@ -0,0 +1,11 @@
+-- Workspace wording of system messages ("Conversation transferred to a
+-- human agent", ...) per locale. Keys without a row use the built-in copy.
+CREATE TABLE
+    IF NOT EXISTS system_message_templates (
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        key TEXT NOT NULL,
+        locale TEXT NOT NULL,
+        template TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (tenant_id, key, locale)
+    );
@@ -0,0 +1,7 @@
+-- Short conversation subject for inbox rows, and who wrote it: '' (none
+-- yet), 'ai' or 'agent'. Agent titles are never replaced by generated ones.
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS title TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS title_source TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,23 @@
+-- Saved inbox views: a named filter and sort owned by an agent, optionally
+-- shared with one of their teams.
+CREATE TABLE
+    IF NOT EXISTS inbox_views (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        name TEXT NOT NULL,
+        filters TEXT NOT NULL DEFAULT '{}',
+        sort TEXT NOT NULL DEFAULT 'priority',
+        team_id TEXT REFERENCES teams (id) ON DELETE SET NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_inbox_views_tenant ON inbox_views (tenant_id);
+
+-- The view each agent's inbox opens with; the view may be a teammate's.
+CREATE TABLE
+    IF NOT EXISTS inbox_view_defaults (
+        agent_id TEXT PRIMARY KEY REFERENCES agents (id) ON DELETE CASCADE,
+        view_id TEXT NOT NULL REFERENCES inbox_views (id) ON DELETE CASCADE
+    );
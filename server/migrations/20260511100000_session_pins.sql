@@ -0,0 +1,13 @@
+-- Sessions an agent pinned to the top of their queue or starred for later.
+-- Marks are personal: teammates see the session unmarked.
+CREATE TABLE
+    IF NOT EXISTS session_pins (
+        agent_id TEXT NOT NULL REFERENCES agents (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        pinned BOOLEAN NOT NULL DEFAULT FALSE,
+        starred BOOLEAN NOT NULL DEFAULT FALSE,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (agent_id, session_id)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_session_pins_session ON session_pins (session_id);
@@ -0,0 +1,4 @@
+-- Seconds after an agent replies during which a teammate's reply to the same
+-- unanswered visitor is held back for confirmation. 0 turns the check off.
+ALTER TABLE tenant_queue_settings
+ADD COLUMN IF NOT EXISTS reply_collision_window_secs INTEGER NOT NULL DEFAULT 30;
@@ -0,0 +1,5 @@
+-- Minutes an open conversation's visitor can wait for a reply before the
+-- inbox is told, as an ascending JSON array. Each threshold fires once per
+-- wait.
+ALTER TABLE tenant_queue_settings
+ADD COLUMN IF NOT EXISTS waiting_alert_mins TEXT NOT NULL DEFAULT '[5,15,30]';
@@ -0,0 +1,31 @@
+-- Notes about a contact that stay with them across conversations.
+CREATE TABLE
+    IF NOT EXISTS contact_notes (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        contact_id TEXT NOT NULL REFERENCES contacts (id) ON DELETE CASCADE,
+        agent_id TEXT NOT NULL,
+        text TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        redacted_at TEXT
+    );
+
+CREATE INDEX IF NOT EXISTS idx_contact_notes_contact ON contact_notes (contact_id, created_at);
+
+-- Contact changes that leave no other trace, such as attribute edits.
+-- Conversations, campaign deliveries and notes are read from their own
+-- tables when the activity feed is built.
+CREATE TABLE
+    IF NOT EXISTS contact_events (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        contact_id TEXT NOT NULL REFERENCES contacts (id) ON DELETE CASCADE,
+        kind TEXT NOT NULL,
+        actor_type TEXT NOT NULL,
+        actor_id TEXT,
+        data TEXT NOT NULL DEFAULT '{}',
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_contact_events_contact ON contact_events (contact_id, created_at);
@@ -0,0 +1,24 @@
+-- People on the visitor side of a conversation, for WhatsApp groups and
+-- email threads with CC'd colleagues. Each one links to their own contact.
+CREATE TABLE
+    IF NOT EXISTS session_participants (
+        id TEXT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL REFERENCES sessions (id) ON DELETE CASCADE,
+        contact_id TEXT REFERENCES contacts (id) ON DELETE SET NULL,
+        handle TEXT NOT NULL,
+        display_name TEXT NOT NULL DEFAULT '',
+        role TEXT NOT NULL DEFAULT 'member',
+        created_at TEXT NOT NULL,
+        UNIQUE (session_id, handle)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_session_participants_contact ON session_participants (contact_id);
+
+-- Which participant wrote a visitor message; the name is kept so history
+-- reads the same after a participant is removed.
+ALTER TABLE chat_messages
+ADD COLUMN IF NOT EXISTS participant_id TEXT;
+
+ALTER TABLE chat_messages
+ADD COLUMN IF NOT EXISTS participant_name TEXT NOT NULL DEFAULT '';
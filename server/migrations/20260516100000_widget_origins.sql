@@ -0,0 +1,5 @@
+-- Web origins (`scheme://host[:port]`) a workspace embeds the widget on, as
+-- a JSON array. Browsers on these origins pass CORS for every workspace's
+-- widget API, but never for agent realtime connections.
+ALTER TABLE tenant_network_rules
+ADD COLUMN IF NOT EXISTS widget_origins TEXT NOT NULL DEFAULT '[]';
@@ -0,0 +1,5 @@
+-- Console sessions held in an HttpOnly cookie carry a CSRF token that
+-- writes must echo. Empty for bearer tokens, which are never accepted from
+-- the cookie.
+ALTER TABLE auth_tokens
+ADD COLUMN IF NOT EXISTS csrf_token TEXT NOT NULL DEFAULT '';
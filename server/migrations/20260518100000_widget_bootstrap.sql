@@ -0,0 +1,13 @@
+-- Secret a workspace's site signs widget identity tokens (HS256 JWTs)
+-- with. Sealed with the workspace data key when encryption is configured.
+CREATE TABLE
+    IF NOT EXISTS tenant_widget_identity (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        secret TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+-- When the visitor last had the widget open on a conversation; agent
+-- replies after it count as unread.
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS visitor_read_at TEXT NOT NULL DEFAULT '';
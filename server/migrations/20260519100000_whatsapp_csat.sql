@@ -0,0 +1,20 @@
+-- Agent who resolved the conversation a rating is for; NULL for ratings
+-- collected without one (widget surveys, flow CSAT nodes).
+ALTER TABLE csat_surveys
+ADD COLUMN IF NOT EXISTS agent_id TEXT;
+
+-- CSAT template sent to a WhatsApp contact after an agent resolved the
+-- conversation. The contact's button reply is matched back through
+-- (tenant_id, visitor_id) while the survey is unanswered.
+CREATE TABLE
+    IF NOT EXISTS whatsapp_csat_requests (
+        session_id TEXT PRIMARY KEY REFERENCES sessions (id) ON DELETE CASCADE,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        visitor_id TEXT NOT NULL,
+        agent_id TEXT,
+        sent_at TEXT NOT NULL,
+        answered_at TEXT
+    );
+
+CREATE INDEX IF NOT EXISTS idx_whatsapp_csat_requests_visitor
+ON whatsapp_csat_requests (tenant_id, visitor_id, sent_at);
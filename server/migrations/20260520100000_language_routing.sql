@@ -0,0 +1,9 @@
+-- Team each detected conversation language is routed to, keyed by primary
+-- language code ({"pt": "<team id>"}).
+ALTER TABLE tenant_queue_settings
+ADD COLUMN IF NOT EXISTS language_teams TEXT NOT NULL DEFAULT '{}';
+
+-- Primary language detected from the first visitor message; empty until
+-- one was recognised.
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS detected_language TEXT NOT NULL DEFAULT '';
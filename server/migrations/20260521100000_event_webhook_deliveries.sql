@@ -0,0 +1,20 @@
+-- Event webhook deliveries, split from the outbox so a slow endpoint never
+-- holds outbox rows (and the realtime emits behind them) locked. The
+-- dispatcher queues one row per emitted event for workspaces with an
+-- enabled webhook; workers claim rows until `claimed_until`, post them and
+-- delete them, or back off through `next_attempt_at`.
+CREATE TABLE
+    IF NOT EXISTS event_webhook_deliveries (
+        seq BIGINT PRIMARY KEY,
+        tenant_id TEXT NOT NULL REFERENCES tenants (id) ON DELETE CASCADE,
+        session_id TEXT NOT NULL DEFAULT '',
+        event TEXT NOT NULL,
+        body TEXT NOT NULL,
+        attempts BIGINT NOT NULL DEFAULT 0,
+        next_attempt_at TEXT NOT NULL DEFAULT '',
+        claimed_until TEXT NOT NULL DEFAULT '',
+        last_error TEXT NOT NULL DEFAULT ''
+    );
+
+CREATE INDEX IF NOT EXISTS idx_event_webhook_deliveries_next_attempt
+ON event_webhook_deliveries (next_attempt_at, seq);
@@ -0,0 +1,6 @@
+-- Set on sessions opened by a widget bootstrap with a verified identity
+-- token. Only these are resumed for `identity:` visitors, so a session
+-- opened elsewhere under a forged `identity:` visitor id is never handed
+-- to the real user.
+ALTER TABLE sessions
+ADD COLUMN IF NOT EXISTS identity_verified BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,8 @@
+-- Webhook retries moved to event_webhook_deliveries, so outbox rows no
+-- longer record their own emits or attempts. A row the old dispatcher left
+-- emitted but undelivered is emitted to clients once more, as after any
+-- dispatcher crash.
+ALTER TABLE event_outbox
+DROP COLUMN IF EXISTS emitted_at,
+DROP COLUMN IF EXISTS attempts,
+DROP COLUMN IF EXISTS last_error;
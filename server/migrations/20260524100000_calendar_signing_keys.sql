@@ -0,0 +1,9 @@
+-- Per-workspace key that signs calendar OAuth state and meeting manage
+-- links; created on first use. Meeting links are mailed out, so they need a
+-- key that survives restarts, unlike the widget session secret.
+CREATE TABLE
+    IF NOT EXISTS tenant_calendar_keys (
+        tenant_id TEXT PRIMARY KEY REFERENCES tenants (id) ON DELETE CASCADE,
+        secret TEXT NOT NULL,
+        created_at TEXT NOT NULL
+    );
@@ -0,0 +1,572 @@
+//! Load test harness: drives synthetic widget visitors against a running
+//! chat server and reports latency percentiles.
+//!
+//! Each visitor opens a session over REST, joins it on the WebSocket and
+//! sends messages at a human cadence, over the socket or `POST
+//! /api/session/{id}/message`. Fan-out latency is the time from sending a
+//! message until the server echoes it back as `message:new`. When the bot
+//! answers with buttons or suggestions, visitors sometimes click one, so
+//! flows get exercised too.
+//!
+//! Bot replies are reported but not part of the fan-out numbers; point the
+//! bench at a workspace without AI replies to measure only the
+//! AI-independent paths.
+//!
+//! ```text
+//! chat-exp-bench --url http://localhost:4000 --tenant <id> --visitors 200 --messages 20
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, Mutex};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+const USAGE: &str = "usage: chat-exp-bench --url <http://host:port> [--tenant <id>] \
+[--visitors 50] [--messages 10] [--cadence-ms 2000] [--jitter-ms 1500] \
+[--ramp-ms 5000] [--transport ws|rest|mixed] [--click-rate 0.5] [--timeout-ms 10000] [--json]";
+
+const OPENERS: &[&str] = &[
+    "Hi there",
+    "Hello, anyone around?",
+    "Good morning",
+    "Hey, quick question",
+];
+
+const QUESTIONS: &[&str] = &[
+    "How do I reset my password?",
+    "Where can I find my invoices?",
+    "Can I change the email on my account?",
+    "What are your opening hours?",
+    "My order hasn't arrived yet, can you check?",
+    "Do you ship internationally?",
+    "How do I cancel my subscription?",
+    "Is there a discount for annual plans?",
+    "The app keeps logging me out",
+    "Can I talk to someone about billing?",
+];
+
+const FOLLOW_UPS: &[&str] = &[
+    "ok",
+    "thanks!",
+    "Got it, one more thing",
+    "That didn't work",
+    "Could you explain that again?",
+    "Perfect, that's all",
+];
+
+#[derive(Clone)]
+struct Config {
+    base_url: String,
+    ws_url: String,
+    tenant_id: Option<String>,
+    visitors: usize,
+    messages: usize,
+    cadence: Duration,
+    jitter: Duration,
+    ramp: Duration,
+    transport: Transport,
+    click_rate: f64,
+    timeout: Duration,
+    json: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Transport {
+    Ws,
+    Rest,
+    Mixed,
+}
+
+impl Config {
+    fn from_args() -> Result<Self, String> {
+        let mut args = HashMap::<String, String>::new();
+        let mut flags = Vec::<String>::new();
+        let mut iter = std::env::args().skip(1).peekable();
+        while let Some(arg) = iter.next() {
+            let Some(key) = arg.strip_prefix("--") else {
+                return Err(format!("unexpected argument {arg}"));
+            };
+            match iter.peek() {
+                Some(value) if !value.starts_with("--") => {
+                    args.insert(key.to_string(), iter.next().unwrap_or_default());
+                }
+                _ => flags.push(key.to_string()),
+            }
+        }
+        if flags.iter().any(|flag| flag == "help") {
+            return Err(USAGE.to_string());
+        }
+        let number = |key: &str, default: u64| -> Result<u64, String> {
+            match args.get(key) {
+                Some(value) => value
+                    .parse::<u64>()
+                    .map_err(|_| format!("--{key} must be a whole number")),
+                None => Ok(default),
+            }
+        };
+        let base_url = args
+            .get("url")
+            .cloned()
+            .or_else(|| std::env::var("BENCH_URL").ok())
+            .ok_or_else(|| USAGE.to_string())?
+            .trim_end_matches('/')
+            .to_string();
+        // No TLS client is built in; target the server's own port, not the
+        // proxy in front of it.
+        let ws_url = match base_url.split_once("://") {
+            Some(("http", rest)) => format!("ws://{rest}/ws"),
+            _ => return Err("--url must start with http://".to_string()),
+        };
+        let transport = match args.get("transport").map(String::as_str) {
+            None | Some("ws") => Transport::Ws,
+            Some("rest") => Transport::Rest,
+            Some("mixed") => Transport::Mixed,
+            Some(other) => return Err(format!("unknown transport {other}")),
+        };
+        let click_rate = match args.get("click-rate") {
+            Some(value) => value
+                .parse::<f64>()
+                .ok()
+                .filter(|rate| (0.0..=1.0).contains(rate))
+                .ok_or_else(|| "--click-rate must be between 0 and 1".to_string())?,
+            None => 0.5,
+        };
+        Ok(Self {
+            base_url,
+            ws_url,
+            tenant_id: args.get("tenant").cloned(),
+            visitors: number("visitors", 50)?.max(1) as usize,
+            messages: number("messages", 10)? as usize,
+            cadence: Duration::from_millis(number("cadence-ms", 2_000)?),
+            jitter: Duration::from_millis(number("jitter-ms", 1_500)?),
+            ramp: Duration::from_millis(number("ramp-ms", 5_000)?),
+            transport,
+            click_rate,
+            timeout: Duration::from_millis(number("timeout-ms", 10_000)?),
+            json: flags.iter().any(|flag| flag == "json"),
+        })
+    }
+}
+
+/// Latency samples in milliseconds and failure counts, per metric.
+#[derive(Default)]
+struct Stats {
+    samples: HashMap<&'static str, Vec<f64>>,
+    errors: HashMap<&'static str, u64>,
+    clicks: u64,
+}
+
+impl Stats {
+    fn record(&mut self, metric: &'static str, elapsed: Duration) {
+        self.samples
+            .entry(metric)
+            .or_default()
+            .push(elapsed.as_secs_f64() * 1000.0);
+    }
+
+    fn fail(&mut self, metric: &'static str) {
+        *self.errors.entry(metric).or_default() += 1;
+    }
+}
+
+/// Tiny xorshift generator; cadence jitter and message choice only need to
+/// differ between visitors.
+struct Rng(u64);
+
+impl Rng {
+    fn seeded(visitor: usize) -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Self((nanos ^ (visitor as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[(self.next() % items.len() as u64) as usize]
+    }
+}
+
+/// Messages in flight on one socket, by text, with the metric and send time.
+type Pending = Arc<Mutex<HashMap<String, (&'static str, Instant)>>>;
+
+#[tokio::main]
+async fn main() {
+    let config = match Config::from_args() {
+        Ok(config) => config,
+        Err(message) => {
+            eprintln!("{message}");
+            std::process::exit(2);
+        }
+    };
+    let http = reqwest::Client::new();
+    let stats = Arc::new(Mutex::new(Stats::default()));
+    eprintln!(
+        "[bench] {} visitors x {} messages against {}",
+        config.visitors, config.messages, config.base_url
+    );
+
+    let started = Instant::now();
+    let mut tasks = Vec::with_capacity(config.visitors);
+    for visitor in 0..config.visitors {
+        let (config, http, stats) = (config.clone(), http.clone(), stats.clone());
+        let delay = config.ramp.mul_f64(visitor as f64 / config.visitors as f64);
+        tasks.push(tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            run_visitor(visitor, &config, &http, &stats).await;
+        }));
+    }
+    for task in tasks {
+        let _ = task.await;
+    }
+
+    let stats = stats.lock().await;
+    report(&stats, started.elapsed(), config.json);
+}
+
+async fn run_visitor(
+    visitor: usize,
+    config: &Config,
+    http: &reqwest::Client,
+    stats: &Arc<Mutex<Stats>>,
+) {
+    let mut rng = Rng::seeded(visitor);
+    let visitor_id = format!("bench-{visitor}-{:x}", rng.next());
+
+    let begin = Instant::now();
+    let mut body = json!({ "visitorId": visitor_id });
+    if let Some(tenant_id) = &config.tenant_id {
+        body["tenantId"] = json!(tenant_id);
+    }
+    let created = http
+        .post(format!("{}/api/session", config.base_url))
+        .json(&body)
+        .send()
+        .await;
+    let session = match created {
+        Ok(response) if response.status().is_success() => {
+            response.json::<Value>().await.unwrap_or(Value::Null)
+        }
+        Ok(response) => {
+            eprintln!(
+                "[bench] visitor {visitor}: session create returned {}",
+                response.status()
+            );
+            stats.lock().await.fail("session_create");
+            return;
+        }
+        Err(err) => {
+            eprintln!("[bench] visitor {visitor}: session create failed: {err}");
+            stats.lock().await.fail("session_create");
+            return;
+        }
+    };
+    stats.lock().await.record("session_create", begin.elapsed());
+    let text_field = |key: &str| {
+        session
+            .get(key)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+    let (session_id, session_token) = (text_field("sessionId"), text_field("sessionToken"));
+
+    let begin = Instant::now();
+    let socket =
+        match tokio::time::timeout(config.timeout, connect_async(config.ws_url.as_str())).await {
+            Ok(Ok((socket, _))) => socket,
+            _ => {
+                stats.lock().await.fail("ws_connect");
+                return;
+            }
+        };
+    stats.lock().await.record("ws_connect", begin.elapsed());
+    let (mut sink, mut stream) = socket.split();
+
+    let envelope = |event: &str, data: Value| {
+        Message::Text(json!({ "event": event, "data": data }).to_string().into())
+    };
+    let join = json!({
+        "sessionId": session_id,
+        "sessionToken": session_token,
+        "tenantId": config.tenant_id,
+        "visitorId": visitor_id,
+    });
+    let joined_at = Instant::now();
+    if sink
+        .send(envelope("hello", json!({ "protocolVersion": 2 })))
+        .await
+        .is_err()
+        || sink.send(envelope("widget:join", join)).await.is_err()
+    {
+        stats.lock().await.fail("ws_join");
+        return;
+    }
+
+    // Reader: matches echoes of our messages, times bot replies and queues
+    // the buttons and suggestions a visitor could click next.
+    let pending: Pending = Arc::default();
+    let last_sent = Arc::new(Mutex::new(None::<Instant>));
+    let (choices_tx, mut choices_rx) = mpsc::unbounded_channel::<String>();
+    let reader = {
+        let (pending, last_sent, stats) = (pending.clone(), last_sent.clone(), stats.clone());
+        tokio::spawn(async move {
+            let mut joined = false;
+            while let Some(Ok(message)) = stream.next().await {
+                let Message::Text(text) = message else {
+                    continue;
+                };
+                let Ok(payload) = serde_json::from_str::<Value>(text.as_str()) else {
+                    continue;
+                };
+                let data = payload.get("data").cloned().unwrap_or(Value::Null);
+                match payload.get("event").and_then(Value::as_str) {
+                    Some("session:history") if !joined => {
+                        joined = true;
+                        stats.lock().await.record("ws_join", joined_at.elapsed());
+                    }
+                    Some("message:new") => {
+                        let text = data.get("text").and_then(Value::as_str).unwrap_or("");
+                        if data.get("sender").and_then(Value::as_str) == Some("visitor") {
+                            if let Some((metric, sent)) = pending.lock().await.remove(text) {
+                                stats.lock().await.record(metric, sent.elapsed());
+                            }
+                            continue;
+                        }
+                        if let Some(sent) = last_sent.lock().await.take() {
+                            stats.lock().await.record("bot_reply", sent.elapsed());
+                        }
+                        for choice in message_choices(&data) {
+                            let _ = choices_tx.send(choice);
+                        }
+                    }
+                    Some("error") => {
+                        eprintln!("[bench] session {}: {}", data["sessionId"], data["message"]);
+                        stats.lock().await.fail("ws_error");
+                    }
+                    _ => {}
+                }
+            }
+        })
+    };
+
+    for index in 0..config.messages {
+        let jitter = config.jitter.mul_f64(rng.unit());
+        tokio::time::sleep(config.cadence + jitter).await;
+
+        let mut choices = Vec::new();
+        while let Ok(choice) = choices_rx.try_recv() {
+            choices.push(choice);
+        }
+        // Clicks must match a button label exactly, so they carry no tag.
+        let (text, clicked) = if !choices.is_empty() && rng.unit() < config.click_rate {
+            let choice = choices[(rng.next() % choices.len() as u64) as usize].clone();
+            (choice, true)
+        } else {
+            let base = match index {
+                0 => rng.pick(OPENERS),
+                1 => rng.pick(QUESTIONS),
+                _ if rng.unit() < 0.6 => rng.pick(FOLLOW_UPS),
+                _ => rng.pick(QUESTIONS),
+            };
+            (format!("{base} [bench {visitor}.{index}]"), false)
+        };
+        if clicked {
+            stats.lock().await.clicks += 1;
+        }
+
+        let over_rest = match config.transport {
+            Transport::Ws => false,
+            Transport::Rest => true,
+            Transport::Mixed => rng.unit() < 0.5,
+        };
+        let metric = if over_rest {
+            "fanout_rest"
+        } else {
+            "fanout_ws"
+        };
+        let _ = sink
+            .send(envelope(
+                "visitor:typing",
+                json!({ "sessionId": session_id, "text": text, "active": true }),
+            ))
+            .await;
+        let sent = Instant::now();
+        pending.lock().await.insert(text.clone(), (metric, sent));
+        *last_sent.lock().await = Some(sent);
+
+        if over_rest {
+            let posted = http
+                .post(format!(
+                    "{}/api/session/{session_id}/message",
+                    config.base_url
+                ))
+                .header("X-Session-Token", &session_token)
+                .json(&json!({ "sender": "visitor", "text": text }))
+                .send()
+                .await;
+            match posted {
+                Ok(response) if response.status().is_success() => {
+                    stats.lock().await.record("rest_post", sent.elapsed());
+                }
+                _ => {
+                    pending.lock().await.remove(&text);
+                    stats.lock().await.fail("rest_post");
+                }
+            }
+        } else {
+            let message = json!({
+                "sessionId": session_id,
+                "sessionToken": session_token,
+                "text": text,
+            });
+            if sink
+                .send(envelope("widget:message", message))
+                .await
+                .is_err()
+            {
+                pending.lock().await.remove(&text);
+                stats.lock().await.fail(metric);
+                break;
+            }
+        }
+    }
+
+    // Give the last echoes time to arrive; whatever is still missing is lost.
+    let deadline = Instant::now() + config.timeout;
+    while Instant::now() < deadline && !pending.lock().await.is_empty() {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let lost = pending
+        .lock()
+        .await
+        .drain()
+        .map(|(_, (metric, _))| metric)
+        .collect::<Vec<_>>();
+    {
+        let mut stats = stats.lock().await;
+        for metric in lost {
+            stats.fail(metric);
+        }
+    }
+    let _ = sink.send(Message::Close(None)).await;
+    reader.abort();
+}
+
+/// Button labels and suggestions offered by a bot or agent message.
+fn message_choices(message: &Value) -> Vec<String> {
+    let label = |item: &Value| {
+        item.get("label")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    let mut choices = message
+        .get("suggestions")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if let Some(widget) = message.get("widget") {
+        for key in ["buttons", "options"] {
+            if let Some(items) = widget.get(key).and_then(Value::as_array) {
+                choices.extend(items.iter().filter_map(label));
+            }
+        }
+    }
+    choices.retain(|choice| !choice.trim().is_empty());
+    choices
+}
+
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((pct / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+fn report(stats: &Stats, elapsed: Duration, as_json: bool) {
+    const METRICS: [&str; 7] = [
+        "session_create",
+        "ws_connect",
+        "ws_join",
+        "fanout_ws",
+        "fanout_rest",
+        "rest_post",
+        "bot_reply",
+    ];
+    let mut rows = Vec::new();
+    for metric in METRICS {
+        let mut samples = stats.samples.get(metric).cloned().unwrap_or_default();
+        let errors = stats.errors.get(metric).copied().unwrap_or(0);
+        if samples.is_empty() && errors == 0 {
+            continue;
+        }
+        samples.sort_by(f64::total_cmp);
+        rows.push(json!({
+            "metric": metric,
+            "count": samples.len(),
+            "errors": errors,
+            "p50": percentile(&samples, 50.0),
+            "p90": percentile(&samples, 90.0),
+            "p99": percentile(&samples, 99.0),
+            "max": samples.last().copied().unwrap_or(0.0),
+        }));
+    }
+    let ws_errors = stats.errors.get("ws_error").copied().unwrap_or(0);
+
+    if as_json {
+        let summary = json!({
+            "elapsedSecs": elapsed.as_secs_f64(),
+            "clicks": stats.clicks,
+            "wsErrors": ws_errors,
+            "metrics": rows,
+        });
+        println!("{summary}");
+        return;
+    }
+    println!(
+        "{:<15} {:>7} {:>7} {:>9} {:>9} {:>9} {:>9}",
+        "metric", "count", "errors", "p50 ms", "p90 ms", "p99 ms", "max ms"
+    );
+    for row in &rows {
+        println!(
+            "{:<15} {:>7} {:>7} {:>9.1} {:>9.1} {:>9.1} {:>9.1}",
+            row["metric"].as_str().unwrap_or_default(),
+            row["count"],
+            row["errors"],
+            row["p50"].as_f64().unwrap_or(0.0),
+            row["p90"].as_f64().unwrap_or(0.0),
+            row["p99"].as_f64().unwrap_or(0.0),
+            row["max"].as_f64().unwrap_or(0.0),
+        );
+    }
+    println!(
+        "\n{:.1}s elapsed, {} flow clicks, {} socket errors",
+        elapsed.as_secs_f64(),
+        stats.clicks,
+        ws_errors
+    );
+}
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+
+use futures_util::future::BoxFuture;
+use serde_json::{json, Value};
+
+use crate::types::{ConnectorDefinition, ConnectorField};
+
+/// Everything a connector gets when a flow `action` node runs it.
+pub struct ConnectorRequest<'a> {
+    pub http: &'a reqwest::Client,
+    /// Decrypted credentials of the connector account.
+    pub credentials: &'a HashMap<String, String>,
+    /// Node parameters with `{{variables}}` already filled in.
+    pub params: &'a HashMap<String, String>,
+    /// Flow variables, for connectors that forward the whole context.
+    pub variables: &'a HashMap<String, String>,
+}
+
+/// An outgoing integration flows can call. Implementations describe their
+/// inputs so the dashboard can render forms, and perform one action.
+pub trait Connector: Send + Sync {
+    fn key(&self) -> &'static str;
+
+    fn definition(&self) -> ConnectorDefinition;
+
+    /// Run the action; returns a short description of what happened.
+    fn execute<'a>(
+        &'a self,
+        request: ConnectorRequest<'a>,
+    ) -> BoxFuture<'a, Result<String, String>>;
+}
+
+static CONNECTORS: [&dyn Connector; 3] =
+    [&SlackConnector, &GoogleSheetsConnector, &ZapierConnector];
+
+pub fn connectors() -> &'static [&'static dyn Connector] {
+    &CONNECTORS
+}
+
+pub fn connector(key: &str) -> Option<&'static dyn Connector> {
+    CONNECTORS.iter().copied().find(|c| c.key() == key)
+}
+
+/// Labels of the required `fields` that are empty in `values`.
+pub fn missing_fields(fields: &[ConnectorField], values: &HashMap<String, String>) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|f| f.required)
+        .filter(|f| values.get(&f.key).is_none_or(|v| v.trim().is_empty()))
+        .map(|f| f.label.clone())
+        .collect()
+}
+
+fn field(key: &str, label: &str, kind: &str, required: bool, placeholder: &str) -> ConnectorField {
+    ConnectorField {
+        key: key.to_string(),
+        label: label.to_string(),
+        kind: kind.to_string(),
+        required,
+        placeholder: placeholder.to_string(),
+    }
+}
+
+fn value<'a>(values: &'a HashMap<String, String>, key: &str) -> &'a str {
+    values.get(key).map(|v| v.trim()).unwrap_or_default()
+}
+
+async fn response_error(response: reqwest::Response, service: &str) -> String {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    format!(
+        "{service} returned {status} {}",
+        body.chars().take(200).collect::<String>()
+    )
+    .trim()
+    .to_string()
+}
+
+// ── Slack ───────────────────────────────────────────────────────────
+
+struct SlackConnector;
+
+impl Connector for SlackConnector {
+    fn key(&self) -> &'static str {
+        "slack"
+    }
+
+    fn definition(&self) -> ConnectorDefinition {
+        ConnectorDefinition {
+            key: self.key().to_string(),
+            label: "Slack".to_string(),
+            description: "Post a message to a channel with a bot token.".to_string(),
+            credential_fields: vec![field("botToken", "Bot token", "secret", true, "xoxb-…")],
+            action_fields: vec![
+                field("channel", "Channel", "text", true, "#support or C0123456"),
+                field(
+                    "text",
+                    "Message",
+                    "textarea",
+                    true,
+                    "New lead: {{contact.email}}",
+                ),
+            ],
+        }
+    }
+
+    fn execute<'a>(
+        &'a self,
+        request: ConnectorRequest<'a>,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let channel = value(request.params, "channel");
+            let response = request
+                .http
+                .post("https://slack.com/api/chat.postMessage")
+                .bearer_auth(value(request.credentials, "botToken"))
+                .json(&json!({ "channel": channel, "text": value(request.params, "text") }))
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+            if !response.status().is_success() {
+                return Err(response_error(response, "Slack").await);
+            }
+            // Slack reports failures as 200 with `ok: false`.
+            let body = response.json::<Value>().await.unwrap_or(Value::Null);
+            if body.get("ok").and_then(Value::as_bool) != Some(true) {
+                return Err(format!(
+                    "Slack error: {}",
+                    body.get("error")
+                        .and_then(Value::as_str)
+                        .unwrap_or("unknown")
+                ));
+            }
+            Ok(format!("posted to {channel}"))
+        })
+    }
+}
+
+// ── Google Sheets ───────────────────────────────────────────────────
+
+struct GoogleSheetsConnector;
+
+impl Connector for GoogleSheetsConnector {
+    fn key(&self) -> &'static str {
+        "google_sheets"
+    }
+
+    fn definition(&self) -> ConnectorDefinition {
+        ConnectorDefinition {
+            key: self.key().to_string(),
+            label: "Google Sheets".to_string(),
+            description: "Append a row to a spreadsheet using an OAuth refresh token.".to_string(),
+            credential_fields: vec![
+                field("clientId", "OAuth client ID", "text", true, ""),
+                field("clientSecret", "OAuth client secret", "secret", true, ""),
+                field("refreshToken", "Refresh token", "secret", true, ""),
+            ],
+            action_fields: vec![
+                field(
+                    "spreadsheetId",
+                    "Spreadsheet ID",
+                    "text",
+                    true,
+                    "1BxiMVs0XRA5nFMd…",
+                ),
+                field("sheet", "Sheet", "text", false, "Sheet1"),
+                field(
+                    "values",
+                    "Row (one cell per line)",
+                    "textarea",
+                    true,
+                    "{{contact.name}}\n{{contact.email}}",
+                ),
+            ],
+        }
+    }
+
+    fn execute<'a>(
+        &'a self,
+        request: ConnectorRequest<'a>,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let token = request
+                .http
+                .post("https://oauth2.googleapis.com/token")
+                .form(&[
+                    ("grant_type", "refresh_token"),
+                    ("client_id", value(request.credentials, "clientId")),
+                    ("client_secret", value(request.credentials, "clientSecret")),
+                    ("refresh_token", value(request.credentials, "refreshToken")),
+                ])
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+            if !token.status().is_success() {
+                return Err(response_error(token, "Google").await);
+            }
+            let token = token.json::<Value>().await.map_err(|e| e.to_string())?;
+            let access_token = token
+                .get("access_token")
+                .and_then(Value::as_str)
+                .ok_or_else(|| "Google returned no access token".to_string())?;
+            let sheet = match value(request.params, "sheet") {
+                "" => "Sheet1",
+                sheet => sheet,
+            };
+            let row = value(request.params, "values")
+                .lines()
+                .map(str::trim)
+                .collect::<Vec<_>>();
+            let mut url = reqwest::Url::parse("https://sheets.googleapis.com/v4/spreadsheets")
+                .map_err(|e| e.to_string())?;
+            url.path_segments_mut()
+                .map_err(|_| "invalid Sheets URL".to_string())?
+                .push(value(request.params, "spreadsheetId"))
+                .push("values")
+                .push(&format!("{sheet}:append"));
+            url.set_query(Some("valueInputOption=USER_ENTERED"));
+            let response = request
+                .http
+                .post(url)
+                .bearer_auth(access_token)
+                .json(&json!({ "values": [row] }))
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+            if !response.status().is_success() {
+                return Err(response_error(response, "Google Sheets").await);
+            }
+            let body = response.json::<Value>().await.unwrap_or(Value::Null);
+            Ok(format!(
+                "appended to {}",
+                body.pointer("/updates/updatedRange")
+                    .and_then(Value::as_str)
+                    .unwrap_or(sheet)
+            ))
+        })
+    }
+}
+
+// ── Zapier ──────────────────────────────────────────────────────────
+
+struct ZapierConnector;
+
+impl Connector for ZapierConnector {
+    fn key(&self) -> &'static str {
+        "zapier"
+    }
+
+    fn definition(&self) -> ConnectorDefinition {
+        ConnectorDefinition {
+            key: self.key().to_string(),
+            label: "Zapier".to_string(),
+            description: "Trigger a Zap through its Catch Hook URL.".to_string(),
+            credential_fields: vec![field(
+                "webhookUrl",
+                "Catch Hook URL",
+                "secret",
+                true,
+                "https://hooks.zapier.com/hooks/catch/…",
+            )],
+            action_fields: vec![field(
+                "payload",
+                "JSON payload",
+                "textarea",
+                false,
+                "Empty sends all flow variables",
+            )],
+        }
+    }
+
+    fn execute<'a>(
+        &'a self,
+        request: ConnectorRequest<'a>,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let url = value(request.credentials, "webhookUrl");
+            if !url.starts_with("https://hooks.zapier.com/") {
+                return Err("Catch Hook URL must start with https://hooks.zapier.com/".to_string());
+            }
+            let payload = match value(request.params, "payload") {
+                // Internal `__` variables only make sense to the flow engine.
+                "" => json!(request
+                    .variables
+                    .iter()
+                    .filter(|(key, _)| !key.starts_with("__"))
+                    .collect::<HashMap<_, _>>()),
+                raw => serde_json::from_str::<Value>(raw)
+                    .map_err(|e| format!("payload is not valid JSON: {e}"))?,
+            };
+            let response = request
+                .http
+                .post(url)
+                .json(&payload)
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+            if !response.status().is_success() {
+                return Err(response_error(response, "Zapier").await);
+            }
+            Ok("zap triggered".to_string())
+        })
+    }
+}
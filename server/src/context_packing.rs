@@ -0,0 +1,264 @@
+//! Token counting and budget-aware packing of the blocks sent to chat models.
+//!
+//! Counts follow how BPE tokenizers split text (words with their leading
+//! space, digit groups, punctuation runs, non-ASCII characters) rather than
+//! plain word counts. They err slightly high so a packed prompt stays under
+//! the model limit.
+
+/// Tokens added per chat message for role and framing.
+const MESSAGE_OVERHEAD_TOKENS: usize = 4;
+/// Tokens that prime the assistant reply.
+const REPLY_PRIMING_TOKENS: usize = 3;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Run {
+    None,
+    Word,
+    Digits,
+    Space,
+    Punct,
+}
+
+fn run_tokens(run: Run, ascii: usize, other: usize) -> usize {
+    match run {
+        Run::None => 0,
+        // Common words are a single token; longer ones split every ~5 chars.
+        Run::Word => {
+            let ascii_tokens = match ascii {
+                0 => 0,
+                1..=7 => 1,
+                len => len.div_ceil(5),
+            };
+            ascii_tokens + other
+        }
+        Run::Digits => ascii.div_ceil(3),
+        Run::Space => usize::from(ascii + other > 1),
+        Run::Punct => ascii.div_ceil(2) + other,
+    }
+}
+
+/// Estimated tokens of `text`.
+pub fn count_tokens(text: &str) -> usize {
+    let mut total = 0;
+    let mut run = Run::None;
+    let (mut ascii, mut other) = (0usize, 0usize);
+    for ch in text.chars() {
+        let kind = if ch.is_alphabetic() {
+            Run::Word
+        } else if ch.is_ascii_digit() {
+            Run::Digits
+        } else if ch.is_whitespace() {
+            Run::Space
+        } else {
+            Run::Punct
+        };
+        if kind != run || ch == '\n' {
+            total += run_tokens(run, ascii, other);
+            run = kind;
+            ascii = 0;
+            other = 0;
+            if ch == '\n' {
+                total += 1;
+                run = Run::None;
+                continue;
+            }
+        }
+        if ch.is_ascii() {
+            ascii += 1;
+        } else {
+            // Accented and CJK characters rarely share a token.
+            other += 1;
+        }
+    }
+    total + run_tokens(run, ascii, other)
+}
+
+/// Estimated prompt tokens of a chat request with these message contents.
+pub fn count_chat_tokens(contents: &[&str]) -> usize {
+    contents
+        .iter()
+        .map(|content| count_tokens(content) + MESSAGE_OVERHEAD_TOKENS)
+        .sum::<usize>()
+        + REPLY_PRIMING_TOKENS
+}
+
+/// Context window of a chat model, in tokens.
+pub fn model_context_window(model: &str) -> usize {
+    let model = model.trim().to_ascii_lowercase();
+    if model.starts_with("gpt-4.1") {
+        1_047_576
+    } else if model.starts_with("gpt-4o")
+        || model.starts_with("gpt-4-turbo")
+        || model.starts_with("o1")
+        || model.starts_with("o3")
+        || model.starts_with("o4")
+    {
+        128_000
+    } else if model.starts_with("gpt-4-32k") {
+        32_768
+    } else if model.starts_with("gpt-4") {
+        8_192
+    } else if model.starts_with("gpt-3.5") {
+        16_385
+    } else {
+        128_000
+    }
+}
+
+pub struct ContextParts<'a> {
+    /// Instructions, visitor message and format hints; always sent whole.
+    pub fixed: &'a str,
+    pub contact_block: &'a str,
+    pub tools_block: &'a str,
+    /// Oldest to newest, one message per line.
+    pub transcript: &'a str,
+    /// Ranked sections separated by blank lines, best first.
+    pub kb_context: &'a str,
+}
+
+#[derive(Debug, Default)]
+pub struct PackedContext {
+    pub contact_block: String,
+    pub tools_block: String,
+    pub transcript: String,
+    pub kb_context: String,
+    /// Estimated tokens of everything kept, the fixed part included.
+    pub tokens: usize,
+    /// Estimated tokens cut to fit the budget.
+    pub trimmed_tokens: usize,
+}
+
+/// Fit the context blocks into `budget` tokens.
+///
+/// The contact and tools blocks are small and kept first, each within a
+/// quarter of what the fixed part leaves. The newest transcript lines get
+/// up to half of the rest, the best KB sections fill what remains, and the
+/// transcript then takes back anything the KB did not use.
+pub fn pack_context(parts: &ContextParts<'_>, budget: usize) -> PackedContext {
+    let fixed = count_tokens(parts.fixed);
+    let available = budget.saturating_sub(fixed);
+    let contact_block = keep_head_lines(parts.contact_block, available / 4);
+    let tools_block = keep_head_lines(parts.tools_block, available / 4);
+    let rest = available
+        .saturating_sub(count_tokens(&contact_block))
+        .saturating_sub(count_tokens(&tools_block));
+    let transcript = keep_tail_lines(parts.transcript, rest / 2);
+    let kb_context = keep_head_sections(
+        parts.kb_context,
+        rest.saturating_sub(count_tokens(&transcript)),
+    );
+    let transcript = keep_tail_lines(
+        parts.transcript,
+        rest.saturating_sub(count_tokens(&kb_context)),
+    );
+
+    let kept = [&contact_block, &tools_block, &transcript, &kb_context]
+        .iter()
+        .map(|block| count_tokens(block))
+        .sum::<usize>();
+    let full = [
+        parts.contact_block,
+        parts.tools_block,
+        parts.transcript,
+        parts.kb_context,
+    ]
+    .iter()
+    .map(|block| count_tokens(block))
+    .sum::<usize>();
+    PackedContext {
+        contact_block,
+        tools_block,
+        transcript,
+        kb_context,
+        tokens: fixed + kept,
+        trimmed_tokens: full.saturating_sub(kept),
+    }
+}
+
+fn keep_head_lines(text: &str, budget: usize) -> String {
+    if count_tokens(text) <= budget {
+        return text.to_string();
+    }
+    let mut used = 0;
+    let mut kept = Vec::new();
+    for line in text.lines() {
+        let cost = count_tokens(line) + 1;
+        if used + cost > budget {
+            break;
+        }
+        used += cost;
+        kept.push(line);
+    }
+    kept.join("\n")
+}
+
+/// Newest lines that fit; a single oversized newest line keeps its end.
+fn keep_tail_lines(text: &str, budget: usize) -> String {
+    if count_tokens(text) <= budget {
+        return text.to_string();
+    }
+    let mut used = 0;
+    let mut kept = Vec::new();
+    for line in text.lines().rev() {
+        let cost = count_tokens(line) + 1;
+        if used + cost > budget {
+            if kept.is_empty() {
+                kept.push(keep_tail_chars(line, budget));
+            }
+            break;
+        }
+        used += cost;
+        kept.push(line.to_string());
+    }
+    kept.reverse();
+    kept.join("\n")
+}
+
+fn keep_tail_chars(line: &str, budget: usize) -> String {
+    let chars = line.chars().collect::<Vec<_>>();
+    // Longest suffix within budget; token counts grow with suffix length.
+    let (mut low, mut high) = (0, chars.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        let suffix = chars[mid..].iter().collect::<String>();
+        if count_tokens(&suffix) <= budget {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    chars[low..].iter().collect()
+}
+
+/// Leading whole sections that fit. Sections start with a `[n]` header
+/// after a blank line, so blank lines inside a section don't split it.
+fn keep_head_sections(text: &str, budget: usize) -> String {
+    if count_tokens(text) <= budget {
+        return text.to_string();
+    }
+    let mut used = 0;
+    let mut kept = Vec::new();
+    for section in kb_sections(text) {
+        let cost = count_tokens(section) + 2;
+        if used + cost > budget {
+            break;
+        }
+        used += cost;
+        kept.push(section);
+    }
+    kept.join("\n\n")
+}
+
+fn kb_sections(text: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    let mut search = 0;
+    while let Some(offset) = text[search..].find("\n\n[") {
+        let split = search + offset;
+        sections.push(&text[start..split]);
+        start = split + 2;
+        search = start;
+    }
+    sections.push(&text[start..]);
+    sections
+}
@@ -0,0 +1,527 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use candle_core::{DType, Device, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::bert::{BertModel, Config as BertConfig};
+use futures_util::future::BoxFuture;
+use serde_json::{json, Value};
+use tokenizers::{PaddingParams, Tokenizer, TruncationParams};
+
+/// Turns text into vectors for KB search. One embedder serves a deployment,
+/// chosen by `EMBEDDING_PROVIDER`; vectors from different embedders are not
+/// comparable, so stored chunks are tagged with `id()`.
+pub trait Embedder: Send + Sync {
+    /// Provider and model, e.g. `openai:text-embedding-3-large`.
+    fn id(&self) -> String;
+
+    fn dimension(&self) -> usize;
+
+    fn embed<'a>(
+        &'a self,
+        http: &'a reqwest::Client,
+        inputs: &'a [String],
+    ) -> BoxFuture<'a, Result<Vec<Vec<f64>>, String>>;
+}
+
+/// Embedder configured by the environment:
+/// - `openai` (default): OpenAI embeddings API.
+/// - `http`: an OpenAI-compatible `/v1/embeddings` server on the network,
+///   e.g. text-embeddings-inference or Ollama serving an ONNX model.
+/// - `local`: a sentence-transformers BERT model run in-process; the files
+///   are read from `EMBEDDING_MODEL_DIR`, downloaded there on first use.
+/// - `hash`: hashed n-gram vectors, a lexical fallback that needs no model.
+pub fn embedder_from_env() -> Box<dyn Embedder> {
+    let var = |key: &str| {
+        std::env::var(key)
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    };
+    let dimension = |default: usize| {
+        var("EMBEDDING_DIMENSION")
+            .parse::<usize>()
+            .ok()
+            .filter(|dim| *dim > 0)
+            .unwrap_or(default)
+    };
+    match var("EMBEDDING_PROVIDER").to_ascii_lowercase().as_str() {
+        "local" => {
+            let model = Some(var("EMBEDDING_MODEL"))
+                .filter(|model| !model.is_empty())
+                .unwrap_or_else(|| LOCAL_EMBEDDING_MODEL.to_string());
+            let dir = Some(var("EMBEDDING_MODEL_DIR"))
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from)
+                .unwrap_or_else(|| {
+                    PathBuf::from("./embedding_models").join(model.replace('/', "--"))
+                });
+            Box::new(LocalEmbedder {
+                model,
+                dir,
+                dimension: dimension(LOCAL_EMBEDDING_DIMENSION),
+                loaded: tokio::sync::OnceCell::new(),
+            })
+        }
+        "hash" => Box::new(HashEmbedder {
+            dimension: dimension(HASH_EMBEDDING_DIMENSION),
+        }),
+        "http" => Box::new(HttpEmbedder {
+            url: var("EMBEDDING_URL"),
+            api_key: var("EMBEDDING_API_KEY"),
+            model: var("EMBEDDING_MODEL"),
+            dimension: dimension(768),
+        }),
+        _ => Box::new(HttpEmbedder {
+            url: "https://api.openai.com/v1/embeddings".to_string(),
+            api_key: var("OPENAI_API_KEY"),
+            model: Some(var("OPENAI_EMBEDDING_MODEL"))
+                .filter(|model| !model.is_empty())
+                .unwrap_or_else(|| "text-embedding-3-large".to_string()),
+            dimension: dimension(3072),
+        }),
+    }
+}
+
+// ── OpenAI-compatible HTTP ──────────────────────────────────────────
+
+struct HttpEmbedder {
+    url: String,
+    api_key: String,
+    model: String,
+    dimension: usize,
+}
+
+impl Embedder for HttpEmbedder {
+    fn id(&self) -> String {
+        if self.url.starts_with("https://api.openai.com/") {
+            format!("openai:{}", self.model)
+        } else {
+            format!("http:{}", self.model)
+        }
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn embed<'a>(
+        &'a self,
+        http: &'a reqwest::Client,
+        inputs: &'a [String],
+    ) -> BoxFuture<'a, Result<Vec<Vec<f64>>, String>> {
+        Box::pin(async move {
+            if inputs.is_empty() {
+                return Ok(vec![]);
+            }
+            if self.url.is_empty() {
+                return Err("EMBEDDING_URL not configured".to_string());
+            }
+            let mut request = http.post(&self.url).json(&json!({
+                "model": self.model,
+                "input": inputs,
+            }));
+            if !self.api_key.is_empty() {
+                request = request.bearer_auth(&self.api_key);
+            } else if self.url.starts_with("https://api.openai.com/") {
+                return Err("OPENAI_API_KEY not configured".to_string());
+            }
+            let response = request
+                .send()
+                .await
+                .map_err(|err| format!("embedding request failed: {err}"))?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(format!("embedding provider returned {status}: {body}"));
+            }
+            let payload = response
+                .json::<Value>()
+                .await
+                .map_err(|err| format!("embedding parse failed: {err}"))?;
+            let data = payload
+                .get("data")
+                .and_then(Value::as_array)
+                .ok_or_else(|| "embedding provider response missing data".to_string())?;
+            let mut out = Vec::with_capacity(data.len());
+            for item in data {
+                let embedding = item
+                    .get("embedding")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| "embedding provider item missing embedding".to_string())?
+                    .iter()
+                    .filter_map(Value::as_f64)
+                    .collect::<Vec<_>>();
+                if embedding.len() != self.dimension {
+                    return Err(format!(
+                        "embedding dimension mismatch: expected {} got {}",
+                        self.dimension,
+                        embedding.len()
+                    ));
+                }
+                out.push(embedding);
+            }
+            Ok(out)
+        })
+    }
+}
+
+// ── Local model ─────────────────────────────────────────────────────
+
+const LOCAL_EMBEDDING_MODEL: &str = "sentence-transformers/all-MiniLM-L6-v2";
+const LOCAL_EMBEDDING_DIMENSION: usize = 384;
+const LOCAL_MODEL_FILES: [&str; 3] = ["config.json", "tokenizer.json", "model.safetensors"];
+/// BERT models read at most 512 positions; the tail of a longer chunk is
+/// left to BM25.
+const LOCAL_MAX_TOKENS: usize = 512;
+
+/// BERT sentence embedder on the CPU: mean of the token states, normalized.
+/// The model is loaded on the first request, after fetching any missing
+/// file from the Hugging Face hub.
+struct LocalEmbedder {
+    model: String,
+    dir: PathBuf,
+    dimension: usize,
+    loaded: tokio::sync::OnceCell<Arc<LocalModel>>,
+}
+
+impl LocalEmbedder {
+    async fn load(&self, http: &reqwest::Client) -> Result<Arc<LocalModel>, String> {
+        let model = self
+            .loaded
+            .get_or_try_init(|| async {
+                tokio::fs::create_dir_all(&self.dir)
+                    .await
+                    .map_err(|err| format!("embedding model dir: {err}"))?;
+                for file in LOCAL_MODEL_FILES {
+                    let path = self.dir.join(file);
+                    if !tokio::fs::try_exists(&path).await.unwrap_or(false) {
+                        self.download(http, file, &path).await?;
+                    }
+                }
+                let dir = self.dir.clone();
+                let model = tokio::task::spawn_blocking(move || LocalModel::open(&dir))
+                    .await
+                    .map_err(|err| format!("embedding model load: {err}"))??;
+                if model.dimension != self.dimension {
+                    return Err(format!(
+                        "embedding dimension mismatch: expected {} got {}",
+                        self.dimension, model.dimension
+                    ));
+                }
+                Ok(Arc::new(model))
+            })
+            .await?;
+        Ok(model.clone())
+    }
+
+    async fn download(
+        &self,
+        http: &reqwest::Client,
+        file: &str,
+        path: &std::path::Path,
+    ) -> Result<(), String> {
+        let url = format!("https://huggingface.co/{}/resolve/main/{file}", self.model);
+        let response = http
+            .get(&url)
+            .send()
+            .await
+            .map_err(|err| format!("embedding model download failed: {err}"))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "embedding model download of {file} returned {}",
+                response.status()
+            ));
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|err| format!("embedding model download failed: {err}"))?;
+        // Written aside first so an interrupted download is not taken for
+        // the model on the next start.
+        let partial = path.with_extension("part");
+        tokio::fs::write(&partial, &bytes)
+            .await
+            .map_err(|err| format!("embedding model save: {err}"))?;
+        tokio::fs::rename(&partial, path)
+            .await
+            .map_err(|err| format!("embedding model save: {err}"))
+    }
+}
+
+impl Embedder for LocalEmbedder {
+    fn id(&self) -> String {
+        format!("local:{}", self.model)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn embed<'a>(
+        &'a self,
+        http: &'a reqwest::Client,
+        inputs: &'a [String],
+    ) -> BoxFuture<'a, Result<Vec<Vec<f64>>, String>> {
+        Box::pin(async move {
+            if inputs.is_empty() {
+                return Ok(vec![]);
+            }
+            let model = self.load(http).await?;
+            let inputs = inputs.to_vec();
+            tokio::task::spawn_blocking(move || model.embed(inputs))
+                .await
+                .map_err(|err| format!("embedding failed: {err}"))?
+        })
+    }
+}
+
+struct LocalModel {
+    bert: BertModel,
+    tokenizer: Tokenizer,
+    dimension: usize,
+}
+
+impl LocalModel {
+    fn open(dir: &std::path::Path) -> Result<Self, String> {
+        let config = std::fs::read_to_string(dir.join("config.json"))
+            .map_err(|err| format!("embedding model config: {err}"))?;
+        let config = serde_json::from_str::<BertConfig>(&config)
+            .map_err(|err| format!("embedding model config: {err}"))?;
+        let tokenizer = Tokenizer::from_file(dir.join("tokenizer.json"))
+            .map_err(|err| format!("embedding model tokenizer: {err}"))?;
+        // SAFETY: the weights file is only ever replaced by renaming a new
+        // file over it, never written in place.
+        let weights = unsafe {
+            VarBuilder::from_mmaped_safetensors(
+                &[dir.join("model.safetensors")],
+                DType::F32,
+                &Device::Cpu,
+            )
+        }
+        .map_err(|err| format!("embedding model weights: {err}"))?;
+        Self::new(&config, weights, tokenizer)
+    }
+
+    fn new(
+        config: &BertConfig,
+        weights: VarBuilder,
+        mut tokenizer: Tokenizer,
+    ) -> Result<Self, String> {
+        let bert = BertModel::load(weights, config)
+            .map_err(|err| format!("embedding model weights: {err}"))?;
+        tokenizer.with_padding(Some(PaddingParams::default()));
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: config.max_position_embeddings.min(LOCAL_MAX_TOKENS),
+                ..TruncationParams::default()
+            }))
+            .map_err(|err| format!("embedding model tokenizer: {err}"))?;
+        Ok(Self {
+            bert,
+            tokenizer,
+            dimension: config.hidden_size,
+        })
+    }
+
+    fn embed(&self, inputs: Vec<String>) -> Result<Vec<Vec<f64>>, String> {
+        self.forward(inputs)
+            .map_err(|err| format!("embedding failed: {err}"))
+    }
+
+    fn forward(&self, inputs: Vec<String>) -> tokenizers::Result<Vec<Vec<f64>>> {
+        let encodings = self.tokenizer.encode_batch(inputs, true)?;
+        let rows = |field: fn(&tokenizers::Encoding) -> &[u32]| {
+            encodings
+                .iter()
+                .map(|encoding| Tensor::new(field(encoding), &Device::Cpu))
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|rows| Tensor::stack(&rows, 0))
+        };
+        let ids = rows(tokenizers::Encoding::get_ids)?;
+        let mask = rows(tokenizers::Encoding::get_attention_mask)?;
+        let states = self.bert.forward(&ids, &ids.zeros_like()?, Some(&mask))?;
+        // Padding positions are masked out of the mean.
+        let mask = mask.to_dtype(DType::F32)?.unsqueeze(2)?;
+        let mean = states
+            .broadcast_mul(&mask)?
+            .sum(1)?
+            .broadcast_div(&mask.sum(1)?)?;
+        let normalized = mean.broadcast_div(&mean.sqr()?.sum_keepdim(1)?.sqrt()?)?;
+        Ok(normalized
+            .to_vec2::<f32>()?
+            .into_iter()
+            .map(|row| row.into_iter().map(f64::from).collect())
+            .collect())
+    }
+}
+
+// ── Hashed n-grams ──────────────────────────────────────────────────
+
+const HASH_EMBEDDING_DIMENSION: usize = 1024;
+
+/// Signed feature hashing of words and character trigrams: a lexical
+/// fallback, not a semantic model. Deterministic and fully offline; BM25
+/// still carries most of the ranking in hybrid search.
+struct HashEmbedder {
+    dimension: usize,
+}
+
+impl HashEmbedder {
+    fn vector(&self, text: &str) -> Vec<f64> {
+        let mut vector = vec![0.0; self.dimension];
+        let mut add = |feature: &str, weight: f64| {
+            let hash = fnv1a(feature);
+            let index = (hash % self.dimension as u64) as usize;
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            vector[index] += sign * weight;
+        };
+        let lower = text.to_lowercase();
+        for word in lower
+            .split(|ch: char| !ch.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            add(word, 1.0);
+            let padded = format!(" {word} ").chars().collect::<Vec<_>>();
+            for gram in padded.windows(3) {
+                add(&gram.iter().collect::<String>(), 0.5);
+            }
+        }
+        let norm = vector.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|v| *v /= norm);
+        }
+        vector
+    }
+}
+
+/// Stored vectors must not change between builds, so no `DefaultHasher`.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+impl Embedder for HashEmbedder {
+    fn id(&self) -> String {
+        // Kept from when this was the `local` provider, so stored vectors
+        // stay valid.
+        format!("local:hash-{}", self.dimension)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn embed<'a>(
+        &'a self,
+        _http: &'a reqwest::Client,
+        inputs: &'a [String],
+    ) -> BoxFuture<'a, Result<Vec<Vec<f64>>, String>> {
+        Box::pin(async move { Ok(inputs.iter().map(|input| self.vector(input)).collect()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_nn::VarMap;
+
+    /// WordPiece tokenizer in the layout of a BERT `tokenizer.json`.
+    fn tokenizer() -> Value {
+        json!({
+            "version": "1.0",
+            "truncation": null,
+            "padding": null,
+            "added_tokens": [],
+            "normalizer": {
+                "type": "BertNormalizer",
+                "clean_text": true,
+                "handle_chinese_chars": true,
+                "strip_accents": null,
+                "lowercase": true
+            },
+            "pre_tokenizer": { "type": "BertPreTokenizer" },
+            "post_processor": {
+                "type": "BertProcessing",
+                "sep": ["[SEP]", 3],
+                "cls": ["[CLS]", 2]
+            },
+            "decoder": null,
+            "model": {
+                "type": "WordPiece",
+                "unk_token": "[UNK]",
+                "continuing_subword_prefix": "##",
+                "max_input_chars_per_word": 100,
+                "vocab": {
+                    "[PAD]": 0, "[UNK]": 1, "[CLS]": 2, "[SEP]": 3, "refund": 4,
+                    "policy": 5, "shipping": 6, "times": 7, "how": 8, "long": 9
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn local_model_embeds_from_its_model_dir() {
+        let config = json!({
+            "vocab_size": 10,
+            "hidden_size": 8,
+            "num_hidden_layers": 1,
+            "num_attention_heads": 2,
+            "intermediate_size": 16,
+            "hidden_act": "gelu",
+            "hidden_dropout_prob": 0.0,
+            "max_position_embeddings": 32,
+            "type_vocab_size": 2,
+            "initializer_range": 0.02,
+            "layer_norm_eps": 1e-12,
+            "pad_token_id": 0,
+            "classifier_dropout": null,
+            "model_type": "bert"
+        });
+        let dir = std::env::temp_dir().join(format!("embedding-model-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create model dir");
+        let weights = VarMap::new();
+        let bert_config = serde_json::from_value::<BertConfig>(config.clone()).expect("config");
+        BertModel::load(
+            VarBuilder::from_varmap(&weights, DType::F32, &Device::Cpu),
+            &bert_config,
+        )
+        .expect("random weights");
+        weights
+            .save(dir.join("model.safetensors"))
+            .expect("save weights");
+        std::fs::write(dir.join("config.json"), config.to_string()).expect("write config");
+        std::fs::write(dir.join("tokenizer.json"), tokenizer().to_string())
+            .expect("write tokenizer");
+
+        let embedder = LocalEmbedder {
+            model: "test/tiny-bert".to_string(),
+            dir: dir.clone(),
+            dimension: 8,
+            loaded: tokio::sync::OnceCell::new(),
+        };
+        let http = reqwest::Client::new();
+        let inputs = [
+            "Refund policy".to_string(),
+            "How long are shipping times".to_string(),
+        ];
+        let batch = embedder.embed(&http, &inputs).await.expect("embed batch");
+        assert_eq!(embedder.id(), "local:test/tiny-bert");
+        assert_eq!(batch.len(), 2);
+        for vector in &batch {
+            assert_eq!(vector.len(), 8);
+            let norm = vector.iter().map(|v| v * v).sum::<f64>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-4);
+        }
+        assert_ne!(batch[0], batch[1]);
+        // Padding the shorter input in a batch must not change its vector.
+        let alone = embedder
+            .embed(&http, &inputs[..1])
+            .await
+            .expect("embed one");
+        for (a, b) in alone[0].iter().zip(&batch[0]) {
+            assert!((a - b).abs() < 1e-4);
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
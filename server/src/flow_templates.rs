@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::redact::eprintln_redacted;
+use crate::types::{FlowBundleFlow, FlowTemplate};
+
+const LEAD_CAPTURE_TEMPLATE: &str = include_str!("flow_templates/lead_capture.json");
+const ORDER_STATUS_TEMPLATE: &str = include_str!("flow_templates/order_status.json");
+const FAQ_DEFLECTION_TEMPLATE: &str = include_str!("flow_templates/faq_deflection.json");
+const CSAT_ON_CLOSE_TEMPLATE: &str = include_str!("flow_templates/csat_on_close.json");
+
+const TEMPLATE_SOURCES: [&str; 4] = [
+    LEAD_CAPTURE_TEMPLATE,
+    ORDER_STATUS_TEMPLATE,
+    FAQ_DEFLECTION_TEMPLATE,
+    CSAT_ON_CLOSE_TEMPLATE,
+];
+
+pub fn flow_templates() -> Vec<FlowTemplate> {
+    TEMPLATE_SOURCES
+        .iter()
+        .filter_map(|source| match serde_json::from_str::<FlowTemplate>(source) {
+            Ok(template) => Some(template),
+            Err(err) => {
+                eprintln_redacted!("[flow_templates] skipping invalid template: {}", err);
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn flow_template(key: &str) -> Option<FlowTemplate> {
+    flow_templates()
+        .into_iter()
+        .find(|template| template.key == key)
+}
+
+/// Fill `[[placeholder]]` tokens in every string of the template's flow.
+/// Missing values fall back to the placeholder default; unknown tokens are
+/// left untouched.
+pub fn render_flow_template(
+    template: &FlowTemplate,
+    values: &HashMap<String, String>,
+) -> FlowBundleFlow {
+    let Ok(re) = Regex::new(r"\[\[\s*([a-z_][a-z0-9_]*)\s*\]\]") else {
+        return template.flow.clone();
+    };
+    let mut resolved = template
+        .placeholders
+        .iter()
+        .map(|placeholder| (placeholder.key.clone(), placeholder.default.clone()))
+        .collect::<HashMap<_, _>>();
+    for (key, value) in values {
+        if resolved.contains_key(key) && !value.trim().is_empty() {
+            resolved.insert(key.clone(), value.trim().to_string());
+        }
+    }
+
+    let Ok(mut flow) = serde_json::to_value(&template.flow) else {
+        return template.flow.clone();
+    };
+    fill_placeholders(&mut flow, &re, &resolved);
+    serde_json::from_value(flow).unwrap_or_else(|_| template.flow.clone())
+}
+
+fn fill_placeholders(value: &mut Value, re: &Regex, values: &HashMap<String, String>) {
+    match value {
+        Value::String(text) if re.is_match(text) => {
+            *text = re
+                .replace_all(text, |caps: &regex::Captures| {
+                    values
+                        .get(&caps[1])
+                        .cloned()
+                        .unwrap_or_else(|| caps[0].to_string())
+                })
+                .into_owned();
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| fill_placeholders(item, re, values)),
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|item| fill_placeholders(item, re, values)),
+        _ => {}
+    }
+}
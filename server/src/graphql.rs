@@ -0,0 +1,694 @@
+//! GraphQL API for the agent console.
+//!
+//! Clients select only the fields they render: nested fields such as a
+//! session's messages or contact are loaded when asked for, and lists are
+//! paginated with opaque cursors. Subscriptions ride on the realtime layer:
+//! each one registers as an agent client of the workspace and receives the
+//! same events the console socket does.
+
+use std::sync::Arc;
+
+use async_graphql::{
+    connection::{Connection, Edge},
+    Context, EmptyMutation, Error, Object, Result, Schema, SimpleObject, Subscription, ID,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::NaiveDate;
+use futures_util::stream::{Stream, StreamExt};
+use serde_json::Value;
+use sqlx::{postgres::PgRow, Row};
+
+use crate::app::{open_message_text, realtime_events};
+use crate::redact::eprintln_redacted;
+use crate::types::{AgentProfile, AppState, ChatMessage};
+
+pub type ApiSchema = Schema<QueryRoot, EmptyMutation, SubscriptionRoot>;
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub fn build_schema() -> ApiSchema {
+    Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .limit_depth(10)
+        .limit_complexity(1000)
+        .finish()
+}
+
+/// The authenticated agent and the workspace their token is scoped to;
+/// added to every request and subscription.
+pub struct Viewer {
+    pub agent: AgentProfile,
+    pub tenant_id: String,
+}
+
+fn request_context<'a>(ctx: &Context<'a>) -> Result<(&'a Arc<AppState>, &'a Viewer)> {
+    Ok((ctx.data::<Arc<AppState>>()?, ctx.data::<Viewer>()?))
+}
+
+fn page_size(first: Option<i32>) -> i64 {
+    first
+        .map(i64::from)
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE)
+}
+
+/// Cursors wrap the sort key and id of the last row seen.
+fn encode_cursor(at: &str, id: &str) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{at}\n{id}"))
+}
+
+fn decode_cursor(cursor: Option<&str>) -> Result<(String, String)> {
+    let Some(cursor) = cursor else {
+        return Ok((String::new(), String::new()));
+    };
+    URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|text| {
+            text.split_once('\n')
+                .map(|(at, id)| (at.to_string(), id.to_string()))
+        })
+        .ok_or_else(|| Error::new("invalid cursor"))
+}
+
+fn db_error(err: sqlx::Error) -> Error {
+    eprintln_redacted!("[graphql] query failed: {err}");
+    Error::new("query failed")
+}
+
+// ── Types ───────────────────────────────────────────────────────────
+
+#[derive(SimpleObject)]
+#[graphql(name = "Agent")]
+pub struct AgentNode {
+    id: ID,
+    name: String,
+    email: String,
+    role: String,
+    status: String,
+    avatar_url: String,
+    title: String,
+}
+
+#[derive(Clone)]
+pub struct SessionNode {
+    id: String,
+    tenant_id: String,
+    channel: String,
+    status: String,
+    priority: String,
+    title: String,
+    assignee_agent_id: Option<String>,
+    team_id: Option<String>,
+    flow_id: Option<String>,
+    contact_id: Option<String>,
+    handover_active: bool,
+    created_at: String,
+    updated_at: String,
+}
+
+const SESSION_COLUMNS: &str = "id, tenant_id, channel, status, priority, title, \
+     assignee_agent_id, team_id, flow_id, contact_id, handover_active, created_at, updated_at";
+
+impl SessionNode {
+    fn from_row(row: &PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            tenant_id: row.get("tenant_id"),
+            channel: row.get("channel"),
+            status: row.get("status"),
+            priority: row.get("priority"),
+            title: row.get("title"),
+            assignee_agent_id: row.get("assignee_agent_id"),
+            team_id: row.get("team_id"),
+            flow_id: row.get("flow_id"),
+            contact_id: row.get("contact_id"),
+            handover_active: row.get("handover_active"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
+
+async fn load_session(state: &AppState, tenant_id: &str, id: &str) -> Result<Option<SessionNode>> {
+    let row = sqlx::query(&format!(
+        "SELECT {SESSION_COLUMNS} FROM sessions \
+         WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL"
+    ))
+    .bind(id)
+    .bind(tenant_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(db_error)?;
+    Ok(row.as_ref().map(SessionNode::from_row))
+}
+
+#[Object(name = "Session")]
+impl SessionNode {
+    async fn id(&self) -> ID {
+        ID(self.id.clone())
+    }
+
+    async fn channel(&self) -> &str {
+        &self.channel
+    }
+
+    /// `open`, `pending`, `snoozed`, `resolved` or `closed`.
+    async fn status(&self) -> &str {
+        &self.status
+    }
+
+    async fn priority(&self) -> &str {
+        &self.priority
+    }
+
+    /// Short subject, generated or set by an agent; empty until there is one.
+    async fn title(&self) -> &str {
+        &self.title
+    }
+
+    async fn assignee_agent_id(&self) -> Option<ID> {
+        self.assignee_agent_id.clone().map(ID)
+    }
+
+    async fn team_id(&self) -> Option<ID> {
+        self.team_id.clone().map(ID)
+    }
+
+    async fn flow_id(&self) -> Option<ID> {
+        self.flow_id.clone().map(ID)
+    }
+
+    async fn handover_active(&self) -> bool {
+        self.handover_active
+    }
+
+    async fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    async fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    async fn contact(&self, ctx: &Context<'_>) -> Result<Option<ContactNode>> {
+        let Some(contact_id) = self.contact_id.as_deref().filter(|id| !id.is_empty()) else {
+            return Ok(None);
+        };
+        let (state, _) = request_context(ctx)?;
+        load_contact(state, &self.tenant_id, contact_id).await
+    }
+
+    /// Messages oldest first. Pass `last` (with `before`) to page backwards
+    /// from the newest message instead.
+    async fn messages(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<String, MessageNode>> {
+        let (state, _) = request_context(ctx)?;
+        let backwards = last.is_some() || before.is_some();
+        let limit = page_size(if backwards { last } else { first });
+        let (cursor_at, cursor_id) = decode_cursor(if backwards {
+            before.as_deref()
+        } else {
+            after.as_deref()
+        })?;
+        let sql = if backwards {
+            "SELECT id, session_id, sender, text, agent_id, agent_name, created_at \
+             FROM chat_messages WHERE session_id = $1 \
+               AND ($2 = '' OR (created_at, id) < ($2, $3)) \
+             ORDER BY created_at DESC, id DESC LIMIT $4"
+        } else {
+            "SELECT id, session_id, sender, text, agent_id, agent_name, created_at \
+             FROM chat_messages WHERE session_id = $1 \
+               AND ($2 = '' OR (created_at, id) > ($2, $3)) \
+             ORDER BY created_at, id LIMIT $4"
+        };
+        let mut rows = sqlx::query(sql)
+            .bind(&self.id)
+            .bind(&cursor_at)
+            .bind(&cursor_id)
+            .bind(limit + 1)
+            .fetch_all(&state.db)
+            .await
+            .map_err(db_error)?;
+        let more = rows.len() as i64 > limit;
+        rows.truncate(limit as usize);
+        if backwards {
+            rows.reverse();
+        }
+        let mut connection = if backwards {
+            Connection::new(more, !cursor_at.is_empty())
+        } else {
+            Connection::new(!cursor_at.is_empty(), more)
+        };
+        for row in rows {
+            let message = MessageNode {
+                id: row.get("id"),
+                session_id: row.get("session_id"),
+                sender: row.get("sender"),
+                text: open_message_text(state, &row.get::<String, _>("text")).await,
+                agent_id: row.get("agent_id"),
+                agent_name: row
+                    .get::<Option<String>, _>("agent_name")
+                    .unwrap_or_default(),
+                created_at: row.get("created_at"),
+            };
+            connection.edges.push(Edge::new(
+                encode_cursor(&message.created_at, &message.id),
+                message,
+            ));
+        }
+        Ok(connection)
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Message")]
+pub struct MessageNode {
+    id: String,
+    session_id: String,
+    /// `visitor`, `agent`, `team`, `note` or `system`.
+    sender: String,
+    text: String,
+    agent_id: Option<String>,
+    agent_name: String,
+    created_at: String,
+}
+
+impl From<ChatMessage> for MessageNode {
+    fn from(message: ChatMessage) -> Self {
+        Self {
+            id: message.id,
+            session_id: message.session_id,
+            sender: message.sender,
+            text: message.text,
+            agent_id: message.agent_id,
+            agent_name: message.agent_name,
+            created_at: message.created_at,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ContactNode {
+    id: String,
+    tenant_id: String,
+    display_name: String,
+    email: String,
+    phone: String,
+    company: String,
+    location: String,
+    avatar_url: String,
+    country: String,
+    city: String,
+    last_seen_at: String,
+    created_at: String,
+    updated_at: String,
+}
+
+const CONTACT_COLUMNS: &str = "id, tenant_id, display_name, email, phone, company, location, \
+     avatar_url, country, city, last_seen_at, created_at, updated_at";
+
+impl ContactNode {
+    fn from_row(row: &PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            tenant_id: row.get("tenant_id"),
+            display_name: row.get("display_name"),
+            email: row.get("email"),
+            phone: row.get("phone"),
+            company: row.get("company"),
+            location: row.get("location"),
+            avatar_url: row.get("avatar_url"),
+            country: row.get("country"),
+            city: row.get("city"),
+            last_seen_at: row.get("last_seen_at"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
+
+async fn load_contact(state: &AppState, tenant_id: &str, id: &str) -> Result<Option<ContactNode>> {
+    let row = sqlx::query(&format!(
+        "SELECT {CONTACT_COLUMNS} FROM contacts WHERE id = $1 AND tenant_id = $2"
+    ))
+    .bind(id)
+    .bind(tenant_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(db_error)?;
+    Ok(row.as_ref().map(ContactNode::from_row))
+}
+
+#[Object(name = "Contact")]
+impl ContactNode {
+    async fn id(&self) -> ID {
+        ID(self.id.clone())
+    }
+
+    async fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    async fn email(&self) -> &str {
+        &self.email
+    }
+
+    async fn phone(&self) -> &str {
+        &self.phone
+    }
+
+    async fn company(&self) -> &str {
+        &self.company
+    }
+
+    async fn location(&self) -> &str {
+        &self.location
+    }
+
+    async fn avatar_url(&self) -> &str {
+        &self.avatar_url
+    }
+
+    async fn country(&self) -> &str {
+        &self.country
+    }
+
+    async fn city(&self) -> &str {
+        &self.city
+    }
+
+    async fn last_seen_at(&self) -> &str {
+        &self.last_seen_at
+    }
+
+    async fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    async fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    /// The contact's conversations, most recently active first.
+    async fn sessions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<Connection<String, SessionNode>> {
+        let (state, _) = request_context(ctx)?;
+        list_sessions(
+            state,
+            &self.tenant_id,
+            SessionFilter::Contact(&self.id),
+            first,
+            after,
+        )
+        .await
+    }
+}
+
+enum SessionFilter<'a> {
+    /// Inbox view: not archived or deleted, optionally by status.
+    Inbox(Option<&'a str>),
+    Archived,
+    Contact(&'a str),
+}
+
+async fn list_sessions(
+    state: &AppState,
+    tenant_id: &str,
+    filter: SessionFilter<'_>,
+    first: Option<i32>,
+    after: Option<String>,
+) -> Result<Connection<String, SessionNode>> {
+    let limit = page_size(first);
+    let (cursor_at, cursor_id) = decode_cursor(after.as_deref())?;
+    let (condition, param) = match filter {
+        SessionFilter::Inbox(status) => (
+            "archived_at IS NULL AND deleted_at IS NULL AND ($5 = '' OR status = $5)",
+            status.unwrap_or_default(),
+        ),
+        SessionFilter::Archived => ("archived_at IS NOT NULL AND deleted_at IS NULL", ""),
+        SessionFilter::Contact(contact_id) => {
+            ("deleted_at IS NULL AND contact_id = $5", contact_id)
+        }
+    };
+    let mut rows = sqlx::query(&format!(
+        "SELECT {SESSION_COLUMNS} FROM sessions \
+         WHERE tenant_id = $1 AND {condition} \
+           AND ($2 = '' OR (updated_at, id) < ($2, $3)) \
+         ORDER BY updated_at DESC, id DESC LIMIT $4"
+    ))
+    .bind(tenant_id)
+    .bind(&cursor_at)
+    .bind(&cursor_id)
+    .bind(limit + 1)
+    .bind(param)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?;
+    let more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+    let mut connection = Connection::new(!cursor_at.is_empty(), more);
+    connection.edges.extend(rows.iter().map(|row| {
+        let session = SessionNode::from_row(row);
+        Edge::new(encode_cursor(&session.updated_at, &session.id), session)
+    }));
+    Ok(connection)
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Flow")]
+pub struct FlowNode {
+    id: ID,
+    name: String,
+    description: String,
+    enabled: bool,
+    created_at: String,
+    updated_at: String,
+}
+
+/// Conversation and message counts for one UTC day.
+#[derive(SimpleObject)]
+#[graphql(name = "DailyStats")]
+pub struct DailyStatsNode {
+    day: String,
+    sessions_created: i64,
+    sessions_resolved: i64,
+    visitor_messages: i64,
+    agent_messages: i64,
+    bot_messages: i64,
+}
+
+// ── Queries ─────────────────────────────────────────────────────────
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn me(&self, ctx: &Context<'_>) -> Result<AgentNode> {
+        let (_, viewer) = request_context(ctx)?;
+        let agent = &viewer.agent;
+        Ok(AgentNode {
+            id: ID(agent.id.clone()),
+            name: agent.name.clone(),
+            email: agent.email.clone(),
+            role: agent.role.clone(),
+            status: agent.status.clone(),
+            avatar_url: agent.avatar_url.clone(),
+            title: agent.title.clone(),
+        })
+    }
+
+    /// Conversations, most recently active first. Without `archived`, lists
+    /// the inbox, optionally narrowed to one `status`.
+    async fn sessions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        status: Option<String>,
+        #[graphql(default)] archived: bool,
+    ) -> Result<Connection<String, SessionNode>> {
+        let (state, viewer) = request_context(ctx)?;
+        let filter = if archived {
+            SessionFilter::Archived
+        } else {
+            SessionFilter::Inbox(status.as_deref())
+        };
+        list_sessions(state, &viewer.tenant_id, filter, first, after).await
+    }
+
+    async fn session(&self, ctx: &Context<'_>, id: ID) -> Result<Option<SessionNode>> {
+        let (state, viewer) = request_context(ctx)?;
+        load_session(state, &viewer.tenant_id, &id).await
+    }
+
+    /// Contacts, most recently updated first; `search` matches name, email
+    /// or phone.
+    async fn contacts(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        search: Option<String>,
+    ) -> Result<Connection<String, ContactNode>> {
+        let (state, viewer) = request_context(ctx)?;
+        let limit = page_size(first);
+        let (cursor_at, cursor_id) = decode_cursor(after.as_deref())?;
+        let pattern = search
+            .as_deref()
+            .map(str::trim)
+            .filter(|search| !search.is_empty())
+            .map(|search| format!("%{}%", search.replace('%', "\\%").replace('_', "\\_")))
+            .unwrap_or_default();
+        let mut rows = sqlx::query(&format!(
+            "SELECT {CONTACT_COLUMNS} FROM contacts \
+             WHERE tenant_id = $1 \
+               AND ($5 = '' OR display_name ILIKE $5 OR email ILIKE $5 OR phone ILIKE $5) \
+               AND ($2 = '' OR (updated_at, id) < ($2, $3)) \
+             ORDER BY updated_at DESC, id DESC LIMIT $4"
+        ))
+        .bind(&viewer.tenant_id)
+        .bind(&cursor_at)
+        .bind(&cursor_id)
+        .bind(limit + 1)
+        .bind(&pattern)
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?;
+        let more = rows.len() as i64 > limit;
+        rows.truncate(limit as usize);
+        let mut connection = Connection::new(!cursor_at.is_empty(), more);
+        connection.edges.extend(rows.iter().map(|row| {
+            let contact = ContactNode::from_row(row);
+            Edge::new(encode_cursor(&contact.updated_at, &contact.id), contact)
+        }));
+        Ok(connection)
+    }
+
+    async fn contact(&self, ctx: &Context<'_>, id: ID) -> Result<Option<ContactNode>> {
+        let (state, viewer) = request_context(ctx)?;
+        load_contact(state, &viewer.tenant_id, &id).await
+    }
+
+    async fn flows(&self, ctx: &Context<'_>) -> Result<Vec<FlowNode>> {
+        let (state, viewer) = request_context(ctx)?;
+        let rows = sqlx::query(
+            "SELECT id, name, description, enabled, created_at, updated_at FROM flows \
+             WHERE tenant_id = $1 ORDER BY created_at",
+        )
+        .bind(&viewer.tenant_id)
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?;
+        Ok(rows
+            .iter()
+            .map(|row| FlowNode {
+                id: ID(row.get("id")),
+                name: row.get("name"),
+                description: row.get("description"),
+                enabled: row.get("enabled"),
+                created_at: row.get("created_at"),
+                updated_at: row.get("updated_at"),
+            })
+            .collect())
+    }
+
+    /// Daily counts between two `YYYY-MM-DD` dates, inclusive; days without
+    /// activity are omitted.
+    async fn daily_stats(
+        &self,
+        ctx: &Context<'_>,
+        from: String,
+        to: String,
+    ) -> Result<Vec<DailyStatsNode>> {
+        let (state, viewer) = request_context(ctx)?;
+        let parse = |day: &str| {
+            NaiveDate::parse_from_str(day.trim(), "%Y-%m-%d")
+                .map_err(|_| Error::new("dates must be YYYY-MM-DD"))
+        };
+        let (from, to) = (parse(&from)?, parse(&to)?);
+        if to < from || (to - from).num_days() > 366 {
+            return Err(Error::new("range must be at most a year, from before to"));
+        }
+        let rows = sqlx::query(
+            "SELECT day, sessions_created, sessions_resolved, visitor_messages, agent_messages, \
+               bot_messages FROM daily_session_stats \
+             WHERE tenant_id = $1 AND day >= $2 AND day <= $3 ORDER BY day",
+        )
+        .bind(&viewer.tenant_id)
+        .bind(from.to_string())
+        .bind(to.to_string())
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?;
+        Ok(rows
+            .iter()
+            .map(|row| DailyStatsNode {
+                day: row.get("day"),
+                sessions_created: row.get("sessions_created"),
+                sessions_resolved: row.get("sessions_resolved"),
+                visitor_messages: row.get("visitor_messages"),
+                agent_messages: row.get("agent_messages"),
+                bot_messages: row.get("bot_messages"),
+            })
+            .collect())
+    }
+}
+
+// ── Subscriptions ───────────────────────────────────────────────────
+
+pub struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    /// A conversation changed: new message, status, assignment, etc.
+    async fn session_updated(&self, ctx: &Context<'_>) -> Result<impl Stream<Item = SessionNode>> {
+        let (state, viewer) = request_context(ctx)?;
+        let state = state.clone();
+        let tenant_id = viewer.tenant_id.clone();
+        let events = realtime_events(state.clone(), tenant_id.clone()).await;
+        Ok(events.filter_map(move |(event, data)| {
+            let state = state.clone();
+            let tenant_id = tenant_id.clone();
+            async move {
+                if event != "session:updated" {
+                    return None;
+                }
+                let id = data.get("id").and_then(Value::as_str)?;
+                load_session(&state, &tenant_id, id).await.ok().flatten()
+            }
+        }))
+    }
+
+    /// New messages in the workspace, or in one session.
+    async fn message_created(
+        &self,
+        ctx: &Context<'_>,
+        session_id: Option<ID>,
+    ) -> Result<impl Stream<Item = MessageNode>> {
+        let (state, viewer) = request_context(ctx)?;
+        let events = realtime_events(state.clone(), viewer.tenant_id.clone()).await;
+        Ok(events.filter_map(move |(event, data)| {
+            let message = (event == "message:new")
+                .then(|| serde_json::from_value::<ChatMessage>(data).ok())
+                .flatten()
+                .filter(|message| {
+                    session_id
+                        .as_ref()
+                        .is_none_or(|id| id.as_str() == message.session_id)
+                });
+            async move { message.map(MessageNode::from) }
+        }))
+    }
+}
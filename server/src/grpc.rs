@@ -0,0 +1,206 @@
+//! gRPC API for backend integrations (`proto/chat.proto`).
+//!
+//! Served on `GRPC_PORT` next to the HTTP server. Calls go through the same
+//! service functions as the REST handlers, and the event stream is a
+//! realtime subscriber like the console socket.
+
+use std::{net::SocketAddr, pin::Pin, sync::Arc};
+
+use axum::{
+    http::{Method, StatusCode},
+    Json,
+};
+use futures_util::stream::{Stream, StreamExt};
+use serde_json::Value;
+use tonic::{transport::Server, Request, Response, Status};
+
+use crate::app::{
+    get_session_summary_db, open_session, post_session_message, realtime_events,
+    tenant_for_session, TenantContext,
+};
+use crate::redact::eprintln_redacted;
+use crate::types::{AgentProfile, AppState, ChatMessage, SessionSummary};
+
+pub mod proto {
+    tonic::include_proto!("chatexp.v1");
+}
+
+use proto::{
+    chat_service_server::{ChatService, ChatServiceServer},
+    session_event::Event,
+    CreateSessionRequest, CreateSessionResponse, Message, PostMessageRequest, PostMessageResponse,
+    Sender, Session, SessionEvent, StreamSessionEventsRequest,
+};
+
+/// Serve the gRPC API until the process exits.
+pub async fn serve(state: Arc<AppState>, port: u16) {
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    println!("chat gRPC server running at http://localhost:{port}");
+    let service = ChatServiceServer::new(ChatGrpc { state });
+    if let Err(err) = Server::builder().add_service(service).serve(addr).await {
+        eprintln_redacted!("[grpc] server failed: {err}");
+    }
+}
+
+struct ChatGrpc {
+    state: Arc<AppState>,
+}
+
+impl ChatGrpc {
+    /// The agent and workspace of the call's bearer token. `call` names the
+    /// RPC in the impersonation audit.
+    async fn authorize<T>(
+        &self,
+        request: &Request<T>,
+        call: &str,
+    ) -> Result<(AgentProfile, String), Status> {
+        let headers = request.metadata().clone().into_headers();
+        let path = format!("/chatexp.v1.ChatService/{call}");
+        let TenantContext { agent, tenant_id } =
+            TenantContext::from_headers(&self.state, &headers, &Method::POST, &path)
+                .await
+                .map_err(unauthenticated)?;
+        Ok((agent, tenant_id))
+    }
+
+    async fn session_in_tenant(&self, session_id: &str, tenant_id: &str) -> Result<(), Status> {
+        if session_id.is_empty() {
+            return Err(Status::invalid_argument("session_id is required"));
+        }
+        if tenant_for_session(&self.state, session_id).await.as_deref() != Some(tenant_id) {
+            return Err(Status::not_found("session not found"));
+        }
+        Ok(())
+    }
+}
+
+fn unauthenticated((_, Json(body)): (StatusCode, Json<Value>)) -> Status {
+    Status::unauthenticated(
+        body.get("error")
+            .and_then(Value::as_str)
+            .unwrap_or("invalid token"),
+    )
+}
+
+impl From<SessionSummary> for Session {
+    fn from(summary: SessionSummary) -> Self {
+        Self {
+            id: summary.id,
+            status: summary.status,
+            channel: summary.channel,
+            assignee_agent_id: summary.assignee_agent_id.unwrap_or_default(),
+            team_id: summary.team_id.unwrap_or_default(),
+            contact_id: summary.contact_id.unwrap_or_default(),
+            visitor_id: summary.visitor_id,
+            handover_active: summary.handover_active,
+            created_at: summary.created_at,
+            updated_at: summary.updated_at,
+        }
+    }
+}
+
+impl From<ChatMessage> for Message {
+    fn from(message: ChatMessage) -> Self {
+        Self {
+            id: message.id,
+            session_id: message.session_id,
+            sender: message.sender,
+            text: message.text,
+            agent_id: message.agent_id.unwrap_or_default(),
+            agent_name: message.agent_name,
+            created_at: message.created_at,
+        }
+    }
+}
+
+type SessionEventStream = Pin<Box<dyn Stream<Item = Result<SessionEvent, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl ChatService for ChatGrpc {
+    async fn create_session(
+        &self,
+        request: Request<CreateSessionRequest>,
+    ) -> Result<Response<CreateSessionResponse>, Status> {
+        let (_, tenant_id) = self.authorize(&request, "CreateSession").await?;
+        let visitor_id = request.into_inner().visitor_id;
+        let session_id = open_session(&self.state, &tenant_id, visitor_id.trim())
+            .await
+            .map_err(Status::resource_exhausted)?;
+        let session = get_session_summary_db(&self.state, &session_id)
+            .await
+            .ok_or_else(|| Status::internal("session was not created"))?;
+        Ok(Response::new(CreateSessionResponse {
+            session: Some(session.into()),
+        }))
+    }
+
+    async fn post_message(
+        &self,
+        request: Request<PostMessageRequest>,
+    ) -> Result<Response<PostMessageResponse>, Status> {
+        let (agent, tenant_id) = self.authorize(&request, "PostMessage").await?;
+        let body = request.into_inner();
+        if body.text.trim().is_empty() {
+            return Err(Status::invalid_argument("text is required"));
+        }
+        self.session_in_tenant(&body.session_id, &tenant_id).await?;
+        let sender = match body.sender() {
+            Sender::Visitor => "visitor",
+            Sender::Team => "team",
+            Sender::Agent | Sender::Unspecified => "agent",
+        };
+        let agent = (sender != "visitor").then_some(&agent);
+        let (session_id, message) =
+            post_session_message(&self.state, &body.session_id, sender, &body.text, agent)
+                .await
+                .ok_or_else(|| Status::failed_precondition("unable to create message"))?;
+        Ok(Response::new(PostMessageResponse {
+            session_id,
+            message: Some(message.into()),
+        }))
+    }
+
+    type StreamSessionEventsStream = SessionEventStream;
+
+    async fn stream_session_events(
+        &self,
+        request: Request<StreamSessionEventsRequest>,
+    ) -> Result<Response<Self::StreamSessionEventsStream>, Status> {
+        let (_, tenant_id) = self.authorize(&request, "StreamSessionEvents").await?;
+        let session_id = request.into_inner().session_id;
+        if !session_id.is_empty() {
+            self.session_in_tenant(&session_id, &tenant_id).await?;
+        }
+        let state = self.state.clone();
+        let events = realtime_events(state.clone(), tenant_id.clone()).await;
+        let events = events.filter_map(move |(event, data)| {
+            let state = state.clone();
+            let tenant_id = tenant_id.clone();
+            let session_id = session_id.clone();
+            async move {
+                let event = match event.as_str() {
+                    "message:new" => {
+                        let message = serde_json::from_value::<ChatMessage>(data).ok()?;
+                        if !session_id.is_empty() && message.session_id != session_id {
+                            return None;
+                        }
+                        Event::MessageCreated(message.into())
+                    }
+                    "session:updated" => {
+                        let id = data.get("id").and_then(Value::as_str)?;
+                        if !session_id.is_empty() && id != session_id {
+                            return None;
+                        }
+                        let summary = get_session_summary_db(&state, id)
+                            .await
+                            .filter(|summary| summary.tenant_id == tenant_id)?;
+                        Event::SessionUpdated(summary.into())
+                    }
+                    _ => return None,
+                };
+                Some(Ok(SessionEvent { event: Some(event) }))
+            }
+        });
+        Ok(Response::new(Box::pin(events)))
+    }
+}
@@ -0,0 +1,462 @@
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use regex::Regex;
+
+/// An email addressed to a workspace forwarding address, normalized from
+/// either a raw MIME message (SES) or Mailgun's parsed form fields.
+#[derive(Debug, Default)]
+pub struct InboundEmail {
+    /// Envelope recipients; the workspace is picked from these.
+    pub recipients: Vec<String>,
+    pub from_address: String,
+    pub from_name: String,
+    /// `Cc` recipients as (display name, address).
+    pub cc: Vec<(String, String)>,
+    pub subject: String,
+    /// Plain-text body without the quoted previous message.
+    pub text: String,
+    pub message_id: String,
+    /// `In-Reply-To` and `References` ids, most recent first.
+    pub thread_ids: Vec<String>,
+    pub attachments: Vec<InboundAttachment>,
+}
+
+#[derive(Debug)]
+pub struct InboundAttachment {
+    pub filename: String,
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Parse a raw RFC 5322 message. Only the parts the email channel uses are
+/// kept: addressing, threading headers, the text body and attachments.
+pub fn parse_mime(raw: &[u8]) -> InboundEmail {
+    let raw = String::from_utf8_lossy(raw);
+    let (headers, body) = split_headers(&raw);
+    let (from_name, from_address) = parse_address(header(&headers, "from").unwrap_or(""));
+    let mut recipients = Vec::new();
+    for name in ["to", "cc", "delivered-to"] {
+        for value in headers_named(&headers, name) {
+            recipients.extend(address_list(value));
+        }
+    }
+
+    let cc = headers_named(&headers, "cc")
+        .flat_map(named_address_list)
+        .collect();
+
+    let mut parts = Parts::default();
+    collect_parts(&headers, body, &mut parts);
+    let text = match (parts.text, parts.html) {
+        (Some(text), _) => text,
+        (None, Some(html)) => html_to_text(&html),
+        (None, None) => String::new(),
+    };
+
+    InboundEmail {
+        recipients,
+        from_address,
+        from_name,
+        cc,
+        subject: decode_words(header(&headers, "subject").unwrap_or("")),
+        text: strip_quoted_reply(&text),
+        message_id: message_ids(header(&headers, "message-id").unwrap_or(""))
+            .into_iter()
+            .next()
+            .unwrap_or_default(),
+        thread_ids: thread_ids(
+            header(&headers, "in-reply-to").unwrap_or(""),
+            header(&headers, "references").unwrap_or(""),
+        ),
+        attachments: parts.attachments,
+    }
+}
+
+/// Ids a reply points at: `In-Reply-To` first, then `References` from the
+/// newest message back to the start of the thread.
+pub fn thread_ids(in_reply_to: &str, references: &str) -> Vec<String> {
+    let mut ids = message_ids(in_reply_to);
+    for id in message_ids(references).into_iter().rev() {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// Message ids in a header value, without the angle brackets.
+pub fn message_ids(value: &str) -> Vec<String> {
+    let bracketed = value
+        .split('<')
+        .skip(1)
+        .filter_map(|chunk| chunk.split_once('>').map(|(id, _)| id.trim()))
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if !bracketed.is_empty() || value.contains('<') {
+        return bracketed;
+    }
+    // Some senders omit the brackets on a lone id.
+    value
+        .split_whitespace()
+        .filter(|id| id.contains('@'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Split `"Name" <user@example.com>` into its display name and lowercased
+/// address.
+pub fn parse_address(value: &str) -> (String, String) {
+    let value = value.trim();
+    match (value.rfind('<'), value.rfind('>')) {
+        (Some(start), Some(end)) if start < end => {
+            let name = value[..start].trim().trim_matches('"').trim();
+            (
+                decode_words(name),
+                value[start + 1..end].trim().to_ascii_lowercase(),
+            )
+        }
+        _ => (String::new(), value.trim_matches('"').to_ascii_lowercase()),
+    }
+}
+
+/// Addresses in a comma-separated header, ignoring commas inside quoted
+/// display names.
+pub fn address_list(value: &str) -> Vec<String> {
+    named_address_list(value)
+        .into_iter()
+        .map(|(_, address)| address)
+        .collect()
+}
+
+/// Like [`address_list`], keeping each display name.
+pub fn named_address_list(value: &str) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in value.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                current.push(c);
+            }
+            ',' if !quoted => entries.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    entries.push(current);
+    entries
+        .iter()
+        .map(|entry| parse_address(entry))
+        .filter(|(_, address)| address.contains('@'))
+        .collect()
+}
+
+/// Drop the quoted previous message mail clients append to replies, so
+/// each email adds only what is new to the conversation.
+pub fn strip_quoted_reply(text: &str) -> String {
+    let attribution = Regex::new(r"^On\b.*\bwrote:\s*$").unwrap();
+    let mut kept = Vec::new();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if attribution.is_match(trimmed)
+            || trimmed.starts_with("-----Original Message-----")
+            || trimmed.starts_with("________________________________")
+        {
+            break;
+        }
+        if trimmed.starts_with('>') {
+            continue;
+        }
+        kept.push(line.trim_end());
+    }
+    let stripped = kept.join("\n").trim().to_string();
+    if stripped.is_empty() {
+        text.trim().to_string()
+    } else {
+        stripped
+    }
+}
+
+#[derive(Default)]
+struct Parts {
+    text: Option<String>,
+    html: Option<String>,
+    attachments: Vec<InboundAttachment>,
+}
+
+fn collect_parts(headers: &[(String, String)], body: &str, parts: &mut Parts) {
+    let (content_type, params) = parse_params(header(headers, "content-type").unwrap_or(""));
+    let content_type = if content_type.is_empty() {
+        "text/plain".to_string()
+    } else {
+        content_type
+    };
+
+    if content_type.starts_with("multipart/") {
+        let Some(boundary) = params.get("boundary") else {
+            return;
+        };
+        for part in split_multipart(body, boundary) {
+            let (part_headers, part_body) = split_headers(part);
+            collect_parts(&part_headers, part_body, parts);
+        }
+        return;
+    }
+
+    let (disposition, disposition_params) =
+        parse_params(header(headers, "content-disposition").unwrap_or(""));
+    let filename = disposition_params
+        .get("filename")
+        .or_else(|| params.get("name"))
+        .map(|name| decode_words(name))
+        .unwrap_or_default();
+    let encoding = header(headers, "content-transfer-encoding")
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+    let bytes = decode_body(body, &encoding);
+
+    let is_body_text = disposition != "attachment"
+        && filename.is_empty()
+        && matches!(content_type.as_str(), "text/plain" | "text/html");
+    if !is_body_text {
+        if !bytes.is_empty() {
+            parts.attachments.push(InboundAttachment {
+                filename,
+                content_type,
+                bytes,
+            });
+        }
+        return;
+    }
+
+    let text = decode_charset(&bytes, params.get("charset").map(String::as_str));
+    let slot = if content_type == "text/html" {
+        &mut parts.html
+    } else {
+        &mut parts.text
+    };
+    if slot.is_none() {
+        *slot = Some(text);
+    }
+}
+
+/// Unfolded `(lowercased name, value)` headers and the body after them.
+fn split_headers(raw: &str) -> (Vec<(String, String)>, &str) {
+    // A part may have no headers at all and start with the blank line.
+    if let Some(body) = raw.strip_prefix("\r\n").or_else(|| raw.strip_prefix('\n')) {
+        return (Vec::new(), body);
+    }
+    let (head, body) = match (raw.find("\r\n\r\n"), raw.find("\n\n")) {
+        (Some(crlf), Some(lf)) if lf < crlf => (&raw[..lf], &raw[lf + 2..]),
+        (Some(crlf), _) => (&raw[..crlf], &raw[crlf + 4..]),
+        (None, Some(lf)) => (&raw[..lf], &raw[lf + 2..]),
+        (None, None) => (raw, ""),
+    };
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in head.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+    (headers, body)
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn headers_named<'a>(
+    headers: &'a [(String, String)],
+    name: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    headers
+        .iter()
+        .filter(move |(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// `text/plain; charset="utf-8"` into the lowercased value and its
+/// parameters. RFC 2231 `name*=charset''value` parameters are decoded.
+fn parse_params(value: &str) -> (String, HashMap<String, String>) {
+    let mut segments = value.split(';');
+    let main = segments.next().unwrap_or("").trim().to_ascii_lowercase();
+    let mut params = HashMap::new();
+    for segment in segments {
+        let Some((key, raw)) = segment.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let raw = raw.trim().trim_matches('"');
+        match key.strip_suffix('*') {
+            Some(key) => {
+                let encoded = raw.splitn(3, '\'').nth(2).unwrap_or(raw);
+                let bytes = decode_percent(encoded);
+                params.insert(
+                    key.to_string(),
+                    String::from_utf8_lossy(&bytes).into_owned(),
+                );
+            }
+            None => {
+                params.entry(key).or_insert_with(|| raw.to_string());
+            }
+        }
+    }
+    (main, params)
+}
+
+fn split_multipart<'a>(body: &'a str, boundary: &str) -> Vec<&'a str> {
+    let delimiter = format!("--{boundary}");
+    let mut parts = Vec::new();
+    // The first chunk is the preamble; the closing delimiter ends with `--`.
+    for chunk in body.split(delimiter.as_str()).skip(1) {
+        if chunk.starts_with("--") {
+            break;
+        }
+        let chunk = chunk
+            .strip_prefix("\r\n")
+            .or_else(|| chunk.strip_prefix('\n'))
+            .unwrap_or(chunk);
+        parts.push(chunk);
+    }
+    parts
+}
+
+fn decode_body(body: &str, encoding: &str) -> Vec<u8> {
+    match encoding {
+        "base64" => {
+            let mut compact = body
+                .chars()
+                .filter(|c| !c.is_ascii_whitespace() && *c != '=')
+                .collect::<String>();
+            while compact.len() % 4 != 0 {
+                compact.push('=');
+            }
+            BASE64.decode(compact).unwrap_or_default()
+        }
+        "quoted-printable" => decode_quoted_printable(body, false),
+        _ => body.trim_end_matches(['\r', '\n']).as_bytes().to_vec(),
+    }
+}
+
+fn decode_quoted_printable(input: &str, underscore_is_space: bool) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'=' if bytes.get(i + 1) == Some(&b'\r') && bytes.get(i + 2) == Some(&b'\n') => i += 3,
+            b'=' if bytes.get(i + 1) == Some(&b'\n') => i += 2,
+            b'=' => match bytes
+                .get(i + 1..i + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                Some(byte) => {
+                    out.push(byte);
+                    i += 3;
+                }
+                None => {
+                    out.push(b'=');
+                    i += 1;
+                }
+            },
+            b'_' if underscore_is_space => {
+                out.push(b' ');
+                i += 1;
+            }
+            byte => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+fn decode_percent(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// UTF-8 and ASCII decode as-is; Latin-1 style charsets map byte for byte.
+fn decode_charset(bytes: &[u8], charset: Option<&str>) -> String {
+    match charset.map(str::to_ascii_lowercase).as_deref() {
+        Some("iso-8859-1" | "latin1" | "windows-1252" | "cp1252") => {
+            bytes.iter().map(|b| *b as char).collect()
+        }
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// Decode RFC 2047 encoded words (`=?utf-8?B?...?=`) in a header value.
+fn decode_words(value: &str) -> String {
+    let re = Regex::new(r"=\?([^?]+)\?([bBqQ])\?([^?]*)\?=").unwrap();
+    let mut out = String::new();
+    let mut last = 0;
+    for caps in re.captures_iter(value) {
+        let whole = caps.get(0).unwrap();
+        let between = &value[last..whole.start()];
+        // Whitespace between two encoded words is not part of the text.
+        if last == 0 || !between.trim().is_empty() {
+            out.push_str(between);
+        }
+        let bytes = if caps[2].eq_ignore_ascii_case("b") {
+            BASE64.decode(caps[3].as_bytes()).unwrap_or_default()
+        } else {
+            decode_quoted_printable(&caps[3], true)
+        };
+        out.push_str(&decode_charset(&bytes, Some(&caps[1])));
+        last = whole.end();
+    }
+    out.push_str(&value[last..]);
+    out.trim().to_string()
+}
+
+fn html_to_text(html: &str) -> String {
+    let hidden = Regex::new(r"(?is)<(style|script|head)\b.*?</(style|script|head)>").unwrap();
+    let breaks = Regex::new(r"(?i)<br\s*/?>|</(p|div|li|tr|h[1-6])>").unwrap();
+    let tags = Regex::new(r"(?s)<[^>]*>").unwrap();
+    let blank_lines = Regex::new(r"\n\s*\n\s*\n+").unwrap();
+    let text = hidden.replace_all(html, "");
+    let text = breaks.replace_all(&text, "\n");
+    let text = tags.replace_all(&text, "");
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    blank_lines.replace_all(text.trim(), "\n\n").into_owned()
+}
@@ -1,3 +1,13 @@
 pub mod app;
+pub mod connectors;
+pub mod context_packing;
+pub mod embeddings;
+pub mod flow_templates;
+pub mod graphql;
+pub mod grpc;
+pub mod inbound_email;
 pub mod prompting;
+pub mod redact;
+pub mod store;
 pub mod types;
+pub mod warehouse;
@@ -1,4 +1,12 @@
 #[tokio::main]
 async fn main() {
-    chat_server::app::run().await;
+    match std::env::args().nth(1).as_deref() {
+        Some("reembed") => chat_server::app::reembed().await,
+        Some("backup") => chat_server::app::backup().await,
+        Some("restore") => chat_server::app::restore().await,
+        Some("warehouse-backfill") => chat_server::app::warehouse_backfill().await,
+        Some("replay-events") => chat_server::app::replay_events().await,
+        Some("rotate-keys") => chat_server::app::rotate_keys().await,
+        _ => chat_server::app::run().await,
+    }
 }
@@ -9,6 +9,7 @@ const AI_JSON_FORMAT_HINT_TOOLS_TEMPLATE: &str =
     include_str!("prompts/ai_json_format_hint_tools.j2");
 const AI_JSON_FORMAT_HINT_BASIC_TEMPLATE: &str =
     include_str!("prompts/ai_json_format_hint_basic.j2");
+const AI_TOOL_CALL_HINT_TEMPLATE: &str = include_str!("prompts/ai_tool_call_hint.j2");
 const FLOW_AI_FALLBACK_PROMPT_TEMPLATE: &str = include_str!("prompts/flow_ai_fallback_prompt.j2");
 const EXTRACT_VARS_SYSTEM_TEMPLATE: &str = include_str!("prompts/extract_vars_system.j2");
 const EXTRACT_VARS_USER_TEMPLATE: &str = include_str!("prompts/extract_vars_user.j2");
@@ -16,6 +17,17 @@ const RERANK_SYSTEM_TEMPLATE: &str = include_str!("prompts/rerank_system.j2");
 const RERANK_USER_TEMPLATE: &str = include_str!("prompts/rerank_user.j2");
 const TOOLS_BLOCK_TEMPLATE: &str = include_str!("prompts/tools_block.j2");
 const KB_BLOCK_TEMPLATE: &str = include_str!("prompts/kb_block.j2");
+const TRANSLATE_SYSTEM_TEMPLATE: &str = include_str!("prompts/translate_system.j2");
+const PRIORITY_SYSTEM_TEMPLATE: &str = include_str!("prompts/priority_system.j2");
+const PRIORITY_USER_TEMPLATE: &str = include_str!("prompts/priority_user.j2");
+const TITLE_SYSTEM_TEMPLATE: &str = include_str!("prompts/title_system.j2");
+const TITLE_USER_TEMPLATE: &str = include_str!("prompts/title_user.j2");
+const TICKET_SUMMARY_SYSTEM_TEMPLATE: &str = include_str!("prompts/ticket_summary_system.j2");
+const TICKET_SUMMARY_USER_TEMPLATE: &str = include_str!("prompts/ticket_summary_user.j2");
+const CRM_SUMMARY_SYSTEM_TEMPLATE: &str = include_str!("prompts/crm_summary_system.j2");
+const CRM_SUMMARY_USER_TEMPLATE: &str = include_str!("prompts/crm_summary_user.j2");
+const MEMORY_SUMMARY_SYSTEM_TEMPLATE: &str = include_str!("prompts/memory_summary_system.j2");
+const MEMORY_SUMMARY_USER_TEMPLATE: &str = include_str!("prompts/memory_summary_user.j2");
 
 pub struct SystemPromptContext<'a> {
     pub workspace_name: &'a str,
@@ -53,6 +65,36 @@ pub struct KbBlockContext<'a> {
     pub kb_context: &'a str,
 }
 
+pub struct TranslateSystemContext<'a> {
+    pub source_lang: &'a str,
+    pub target_lang: &'a str,
+    pub glossary: &'a str,
+}
+
+pub struct PriorityUserContext<'a> {
+    pub plan_tier: &'a str,
+    pub visitor_messages: &'a str,
+}
+
+pub struct TitleUserContext<'a> {
+    pub visitor_messages: &'a str,
+}
+
+pub struct TicketSummaryUserContext<'a> {
+    pub contact_block: &'a str,
+    pub transcript: &'a str,
+}
+
+pub struct CrmSummaryUserContext<'a> {
+    pub contact_block: &'a str,
+    pub transcript: &'a str,
+}
+
+pub struct MemorySummaryUserContext<'a> {
+    pub previous_summary: &'a str,
+    pub transcript: &'a str,
+}
+
 fn render_with<F>(template_name: &str, template: &str, build_ctx: F) -> Option<String>
 where
     F: FnOnce() -> minijinja::Value,
@@ -101,6 +143,15 @@ pub fn render_ai_json_format_hint(has_tools: bool) -> String {
     })
 }
 
+pub fn render_ai_tool_call_hint(has_tools: bool) -> String {
+    render_with("ai_tool_call_hint", AI_TOOL_CALL_HINT_TEMPLATE, || {
+        context! {
+            has_tools => has_tools,
+        }
+    })
+    .unwrap_or_else(|| AI_TOOL_CALL_HINT_TEMPLATE.to_string())
+}
+
 pub fn render_ai_user_content(ctx: &AiUserContentContext<'_>) -> String {
     render_with("ai_user_content", AI_USER_CONTENT_TEMPLATE, || {
         context! {
@@ -191,6 +242,22 @@ pub fn render_kb_block(ctx: &KbBlockContext<'_>) -> String {
     .unwrap_or_else(|| ctx.kb_context.to_string())
 }
 
+pub fn render_translate_system_prompt(ctx: &TranslateSystemContext<'_>) -> String {
+    render_with("translate_system", TRANSLATE_SYSTEM_TEMPLATE, || {
+        context! {
+            source_lang => ctx.source_lang,
+            target_lang => ctx.target_lang,
+            glossary => ctx.glossary,
+        }
+    })
+    .unwrap_or_else(|| {
+        format!(
+            "Translate the message into {}. Reply with the translation only.\n{}",
+            ctx.target_lang, ctx.glossary
+        )
+    })
+}
+
 fn fallback_system_prompt(ctx: &SystemPromptContext<'_>) -> String {
     render_with("system_prompt_fallback", SYSTEM_PROMPT_FALLBACK_TEMPLATE, || {
         context! {
@@ -204,3 +271,85 @@ fn fallback_system_prompt(ctx: &SystemPromptContext<'_>) -> String {
     })
     .unwrap_or_else(|| "Prompt rendering failed".to_string())
 }
+
+pub fn render_priority_system_prompt() -> String {
+    render_with("priority_system", PRIORITY_SYSTEM_TEMPLATE, || context! {})
+        .unwrap_or_else(|| PRIORITY_SYSTEM_TEMPLATE.to_string())
+}
+
+pub fn render_priority_user_prompt(ctx: &PriorityUserContext<'_>) -> String {
+    render_with("priority_user", PRIORITY_USER_TEMPLATE, || {
+        context! {
+            plan_tier => ctx.plan_tier,
+            visitor_messages => ctx.visitor_messages,
+        }
+    })
+    .unwrap_or_else(|| [ctx.plan_tier, ctx.visitor_messages].join("\n"))
+}
+
+pub fn render_title_system_prompt() -> String {
+    render_with("title_system", TITLE_SYSTEM_TEMPLATE, || context! {})
+        .unwrap_or_else(|| TITLE_SYSTEM_TEMPLATE.to_string())
+}
+
+pub fn render_title_user_prompt(ctx: &TitleUserContext<'_>) -> String {
+    render_with("title_user", TITLE_USER_TEMPLATE, || {
+        context! {
+            visitor_messages => ctx.visitor_messages,
+        }
+    })
+    .unwrap_or_else(|| ctx.visitor_messages.to_string())
+}
+
+pub fn render_ticket_summary_system_prompt() -> String {
+    render_with("ticket_summary_system", TICKET_SUMMARY_SYSTEM_TEMPLATE, || context! {})
+        .unwrap_or_else(|| TICKET_SUMMARY_SYSTEM_TEMPLATE.to_string())
+}
+
+pub fn render_ticket_summary_user_prompt(ctx: &TicketSummaryUserContext<'_>) -> String {
+    render_with("ticket_summary_user", TICKET_SUMMARY_USER_TEMPLATE, || {
+        context! {
+            contact_block => ctx.contact_block,
+            transcript => ctx.transcript,
+        }
+    })
+    .unwrap_or_else(|| [ctx.contact_block, ctx.transcript].join("\n"))
+}
+
+pub fn render_crm_summary_system_prompt() -> String {
+    render_with(
+        "crm_summary_system",
+        CRM_SUMMARY_SYSTEM_TEMPLATE,
+        || context! {},
+    )
+    .unwrap_or_else(|| CRM_SUMMARY_SYSTEM_TEMPLATE.to_string())
+}
+
+pub fn render_crm_summary_user_prompt(ctx: &CrmSummaryUserContext<'_>) -> String {
+    render_with("crm_summary_user", CRM_SUMMARY_USER_TEMPLATE, || {
+        context! {
+            contact_block => ctx.contact_block,
+            transcript => ctx.transcript,
+        }
+    })
+    .unwrap_or_else(|| [ctx.contact_block, ctx.transcript].join("\n"))
+}
+
+pub fn render_memory_summary_system_prompt() -> String {
+    render_with(
+        "memory_summary_system",
+        MEMORY_SUMMARY_SYSTEM_TEMPLATE,
+        || context! {},
+    )
+    .unwrap_or_else(|| MEMORY_SUMMARY_SYSTEM_TEMPLATE.to_string())
+}
+
+pub fn render_memory_summary_user_prompt(ctx: &MemorySummaryUserContext<'_>) -> String {
+    render_with("memory_summary_user", MEMORY_SUMMARY_USER_TEMPLATE, || {
+        context! {
+            previous_summary => ctx.previous_summary,
+            transcript => ctx.transcript,
+        }
+    })
+    .unwrap_or_else(|| [ctx.previous_summary, ctx.transcript].join("\n"))
+}
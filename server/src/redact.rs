@@ -0,0 +1,134 @@
+//! Redaction for text that leaves the server as logs or error details.
+//!
+//! Provider responses, request errors and visitor identifiers end up in
+//! `eprintln_redacted!` lines and in error bodies. Everything passed through
+//! here has bearer tokens, channel secrets and visitor contact details
+//! masked, keeping just enough (an email domain, a phone number's last four
+//! digits) to tell failures apart.
+
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+use serde_json::Value;
+
+const REDACTED: &str = "[redacted]";
+
+static AUTH_HEADER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(bearer|basic)\s+[A-Za-z0-9._~+/=-]{8,}").unwrap());
+/// `accessToken: x`, `"app_secret":"x"` and `?access_token=x`.
+static SECRET_FIELD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?i)("?[a-z_-]*(?:token|secret|api_?key|private_?key|password)"?\s*[:=]\s*"?)([^"&\s,;}]+)"#,
+    )
+    .unwrap()
+});
+/// Meta Graph API tokens and JWTs that appear without a key.
+static BARE_TOKEN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(?:EAA[A-Za-z0-9]{20,}|eyJ[\w-]{8,}\.[\w-]{8,}\.[\w-]+)").unwrap()
+});
+static EMAIL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b([A-Za-z0-9._%+-])[A-Za-z0-9._%+-]*@([A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)+)\b")
+        .unwrap()
+});
+/// Numbers written like phone numbers: with a leading `+`, or in groups
+/// such as `(555) 123-4567`. Bare digit runs (timestamps, ids) are left
+/// alone.
+static PHONE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\+\d(?:[ .()-]{0,2}\d){7,14}\b|\(?\b\d{2,5}\)?[ .-]\d{3,5}[ .-]\d{4}\b").unwrap()
+});
+
+/// `eprintln!` with [`redact_secrets`] applied to the formatted line.
+macro_rules! eprintln_redacted {
+    ($($arg:tt)*) => {
+        eprintln!("{}", $crate::redact::redact_secrets(&format!($($arg)*)))
+    };
+}
+pub(crate) use eprintln_redacted;
+
+/// Whether an object key names a credential, compared without case, `_`
+/// or `-` (`accessToken`, `app_secret`, `X-Api-Key`).
+fn is_secret_key(key: &str) -> bool {
+    let key = key
+        .chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .collect::<String>()
+        .to_ascii_lowercase();
+    key == "authorization"
+        || key.ends_with("token")
+        || key.ends_with("secret")
+        || key.ends_with("apikey")
+        || key.ends_with("privatekey")
+        || key.contains("password")
+}
+
+/// Mask credentials, emails and phone numbers in free text.
+pub fn redact_secrets(text: &str) -> String {
+    let redacted = AUTH_HEADER.replace_all(text, "$1 [redacted]");
+    let redacted = SECRET_FIELD.replace_all(&redacted, "${1}[redacted]");
+    let redacted = BARE_TOKEN.replace_all(&redacted, REDACTED);
+    let redacted = EMAIL.replace_all(&redacted, "$1***@$2");
+    PHONE
+        .replace_all(&redacted, |caps: &Captures| {
+            let digits = caps[0]
+                .chars()
+                .filter(char::is_ascii_digit)
+                .collect::<String>();
+            format!("***{}", &digits[digits.len() - 4..])
+        })
+        .into_owned()
+}
+
+/// [`redact_secrets`] over a JSON value: credential keys are masked whole,
+/// every other string is redacted as text.
+pub fn redact_value(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(_) if is_secret_key(key) => Value::String(REDACTED.into()),
+                        other => redact_value(other),
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_value).collect()),
+        Value::String(text) => Value::String(redact_secrets(text)),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phone_numbers_keep_their_last_four_digits() {
+        assert_eq!(redact_secrets("call +55 11 98765-4321"), "call ***4321");
+        assert_eq!(redact_secrets("to +14155550123."), "to ***0123.");
+        assert_eq!(redact_secrets("(555) 123-4567 failed"), "***4567 failed");
+    }
+
+    #[test]
+    fn timestamps_and_ids_survive() {
+        let line = "message 1747051234567 for order 202605011234 at 2026-05-01T10:00:00Z";
+        assert_eq!(redact_secrets(line), line);
+    }
+
+    #[test]
+    fn tokens_and_emails_are_masked() {
+        assert_eq!(
+            redact_secrets("Authorization: Bearer abcdefgh12345678"),
+            "Authorization: Bearer [redacted]"
+        );
+        assert_eq!(
+            redact_secrets("{\"access_token\":\"s3cr3t\"}"),
+            "{\"access_token\":\"[redacted]\"}"
+        );
+        assert_eq!(
+            redact_secrets("from jane.doe@example.com"),
+            "from j***@example.com"
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use futures_util::future::BoxFuture;
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Row};
+
+use crate::types::{ChatFlow, FlowEdge, FlowNode};
+
+/// Where a paused flow picks up on the visitor's next message.
+#[derive(Debug, Clone)]
+pub struct FlowCursor {
+    pub tenant_id: String,
+    pub session_id: String,
+    pub flow_id: String,
+    pub node_id: String,
+    pub node_type: String,
+    pub variables: HashMap<String, String>,
+    pub created_at: String,
+}
+
+/// Persistence behind the flow engine. `PgStore` serves the server and
+/// `MemoryStore` keeps everything in process for tests and demo runs.
+///
+/// The store covers the flow engine's state only: which workspace a session
+/// belongs to, the workspace's flows and the cursors of paused runs.
+/// Sessions, messages, contacts and the rest of the schema are read from
+/// `AppState::db` directly, so handler tests still run against Postgres and
+/// there is no database-free demo mode.
+pub trait Store: Send + Sync {
+    fn session_tenant<'a>(&'a self, session_id: &'a str) -> BoxFuture<'a, Option<String>>;
+
+    fn flow<'a>(&'a self, flow_id: &'a str) -> BoxFuture<'a, Option<ChatFlow>>;
+
+    /// The workspace's flows, oldest first.
+    fn tenant_flows<'a>(&'a self, tenant_id: &'a str) -> BoxFuture<'a, Vec<ChatFlow>>;
+
+    /// Oldest enabled flow, which new conversations start on.
+    fn default_flow_id<'a>(&'a self, tenant_id: &'a str) -> BoxFuture<'a, Option<String>>;
+
+    fn flow_cursor<'a>(
+        &'a self,
+        tenant_id: &'a str,
+        session_id: &'a str,
+    ) -> BoxFuture<'a, Option<FlowCursor>>;
+
+    /// Insert or replace the session's cursor.
+    fn save_flow_cursor(&self, cursor: FlowCursor) -> BoxFuture<'_, ()>;
+
+    fn clear_flow_cursor<'a>(
+        &'a self,
+        tenant_id: &'a str,
+        session_id: &'a str,
+    ) -> BoxFuture<'a, ()>;
+}
+
+// ── Postgres ────────────────────────────────────────────────────────
+
+const FLOW_COLUMNS: &str = "id, tenant_id, name, description, enabled, created_at, updated_at, nodes, edges, input_variables, ai_tool, ai_tool_description, max_steps, version";
+
+fn flow_from_row(row: &PgRow) -> ChatFlow {
+    ChatFlow {
+        id: row.get("id"),
+        tenant_id: row.get("tenant_id"),
+        name: row.get("name"),
+        description: row.get("description"),
+        enabled: row.get("enabled"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+        nodes: serde_json::from_str::<Vec<FlowNode>>(&row.get::<String, _>("nodes"))
+            .unwrap_or_default(),
+        edges: serde_json::from_str::<Vec<FlowEdge>>(&row.get::<String, _>("edges"))
+            .unwrap_or_default(),
+        input_variables: serde_json::from_str(&row.get::<String, _>("input_variables"))
+            .unwrap_or_default(),
+        ai_tool: row.get("ai_tool"),
+        ai_tool_description: row.get("ai_tool_description"),
+        max_steps: row.get("max_steps"),
+        version: row.get("version"),
+    }
+}
+
+pub struct PgStore {
+    pool: PgPool,
+}
+
+impl PgStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl Store for PgStore {
+    fn session_tenant<'a>(&'a self, session_id: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(async move {
+            sqlx::query_scalar::<_, String>("SELECT tenant_id FROM sessions WHERE id = $1")
+                .bind(session_id)
+                .fetch_optional(&self.pool)
+                .await
+                .ok()
+                .flatten()
+        })
+    }
+
+    fn flow<'a>(&'a self, flow_id: &'a str) -> BoxFuture<'a, Option<ChatFlow>> {
+        Box::pin(async move {
+            let row = sqlx::query(&format!("SELECT {FLOW_COLUMNS} FROM flows WHERE id = $1"))
+                .bind(flow_id)
+                .fetch_optional(&self.pool)
+                .await
+                .ok()
+                .flatten()?;
+            Some(flow_from_row(&row))
+        })
+    }
+
+    fn tenant_flows<'a>(&'a self, tenant_id: &'a str) -> BoxFuture<'a, Vec<ChatFlow>> {
+        Box::pin(async move {
+            sqlx::query(&format!(
+                "SELECT {FLOW_COLUMNS} FROM flows WHERE tenant_id = $1 ORDER BY created_at ASC"
+            ))
+            .bind(tenant_id)
+            .fetch_all(&self.pool)
+            .await
+            .unwrap_or_default()
+            .iter()
+            .map(flow_from_row)
+            .collect()
+        })
+    }
+
+    fn default_flow_id<'a>(&'a self, tenant_id: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(async move {
+            sqlx::query_scalar::<_, String>(
+                "SELECT id FROM flows WHERE tenant_id = $1 AND enabled = true ORDER BY created_at ASC LIMIT 1",
+            )
+            .bind(tenant_id)
+            .fetch_optional(&self.pool)
+            .await
+            .ok()
+            .flatten()
+        })
+    }
+
+    fn flow_cursor<'a>(
+        &'a self,
+        tenant_id: &'a str,
+        session_id: &'a str,
+    ) -> BoxFuture<'a, Option<FlowCursor>> {
+        Box::pin(async move {
+            let row = sqlx::query(
+                "SELECT flow_id, node_id, node_type, variables, created_at FROM flow_cursors WHERE tenant_id = $1 AND session_id = $2",
+            )
+            .bind(tenant_id)
+            .bind(session_id)
+            .fetch_optional(&self.pool)
+            .await
+            .ok()
+            .flatten()?;
+            Some(FlowCursor {
+                tenant_id: tenant_id.to_string(),
+                session_id: session_id.to_string(),
+                flow_id: row.get("flow_id"),
+                node_id: row.get("node_id"),
+                node_type: row.get("node_type"),
+                variables: serde_json::from_str(&row.get::<String, _>("variables"))
+                    .unwrap_or_default(),
+                created_at: row.get("created_at"),
+            })
+        })
+    }
+
+    fn save_flow_cursor(&self, cursor: FlowCursor) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            let vars_json =
+                serde_json::to_string(&cursor.variables).unwrap_or_else(|_| "{}".to_string());
+            let _ = sqlx::query(
+                "INSERT INTO flow_cursors (tenant_id, session_id, flow_id, node_id, node_type, variables, created_at) \
+                 VALUES ($1, $2, $3, $4, $5, $6, $7) \
+                 ON CONFLICT (tenant_id, session_id) DO UPDATE SET flow_id = $3, node_id = $4, node_type = $5, variables = $6, created_at = $7",
+            )
+            .bind(&cursor.tenant_id)
+            .bind(&cursor.session_id)
+            .bind(&cursor.flow_id)
+            .bind(&cursor.node_id)
+            .bind(&cursor.node_type)
+            .bind(&vars_json)
+            .bind(&cursor.created_at)
+            .execute(&self.pool)
+            .await;
+        })
+    }
+
+    fn clear_flow_cursor<'a>(
+        &'a self,
+        tenant_id: &'a str,
+        session_id: &'a str,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let _ =
+                sqlx::query("DELETE FROM flow_cursors WHERE tenant_id = $1 AND session_id = $2")
+                    .bind(tenant_id)
+                    .bind(session_id)
+                    .execute(&self.pool)
+                    .await;
+        })
+    }
+}
+
+// ── In memory ───────────────────────────────────────────────────────
+
+/// Process-local store. Sessions and flows are seeded with `insert_session`
+/// and `insert_flow`; nothing survives a restart.
+#[derive(Default)]
+pub struct MemoryStore {
+    session_tenants: Mutex<HashMap<String, String>>,
+    flows: Mutex<HashMap<String, ChatFlow>>,
+    cursors: Mutex<HashMap<(String, String), FlowCursor>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert_session(&self, session_id: &str, tenant_id: &str) {
+        if let Ok(mut sessions) = self.session_tenants.lock() {
+            sessions.insert(session_id.to_string(), tenant_id.to_string());
+        }
+    }
+
+    pub fn insert_flow(&self, flow: ChatFlow) {
+        if let Ok(mut flows) = self.flows.lock() {
+            flows.insert(flow.id.clone(), flow);
+        }
+    }
+}
+
+impl Store for MemoryStore {
+    fn session_tenant<'a>(&'a self, session_id: &'a str) -> BoxFuture<'a, Option<String>> {
+        let tenant = self
+            .session_tenants
+            .lock()
+            .ok()
+            .and_then(|sessions| sessions.get(session_id).cloned());
+        Box::pin(async move { tenant })
+    }
+
+    fn flow<'a>(&'a self, flow_id: &'a str) -> BoxFuture<'a, Option<ChatFlow>> {
+        let flow = self
+            .flows
+            .lock()
+            .ok()
+            .and_then(|flows| flows.get(flow_id).cloned());
+        Box::pin(async move { flow })
+    }
+
+    fn tenant_flows<'a>(&'a self, tenant_id: &'a str) -> BoxFuture<'a, Vec<ChatFlow>> {
+        let mut flows = self
+            .flows
+            .lock()
+            .map(|flows| {
+                flows
+                    .values()
+                    .filter(|flow| flow.tenant_id == tenant_id)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        flows.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Box::pin(async move { flows })
+    }
+
+    fn default_flow_id<'a>(&'a self, tenant_id: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(async move {
+            self.tenant_flows(tenant_id)
+                .await
+                .into_iter()
+                .find(|flow| flow.enabled)
+                .map(|flow| flow.id)
+        })
+    }
+
+    fn flow_cursor<'a>(
+        &'a self,
+        tenant_id: &'a str,
+        session_id: &'a str,
+    ) -> BoxFuture<'a, Option<FlowCursor>> {
+        let key = (tenant_id.to_string(), session_id.to_string());
+        let cursor = self
+            .cursors
+            .lock()
+            .ok()
+            .and_then(|cursors| cursors.get(&key).cloned());
+        Box::pin(async move { cursor })
+    }
+
+    fn save_flow_cursor(&self, cursor: FlowCursor) -> BoxFuture<'_, ()> {
+        if let Ok(mut cursors) = self.cursors.lock() {
+            let key = (cursor.tenant_id.clone(), cursor.session_id.clone());
+            cursors.insert(key, cursor);
+        }
+        Box::pin(async {})
+    }
+
+    fn clear_flow_cursor<'a>(
+        &'a self,
+        tenant_id: &'a str,
+        session_id: &'a str,
+    ) -> BoxFuture<'a, ()> {
+        if let Ok(mut cursors) = self.cursors.lock() {
+            cursors.remove(&(tenant_id.to_string(), session_id.to_string()));
+        }
+        Box::pin(async {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flow(id: &str, tenant_id: &str, enabled: bool, created_at: &str) -> ChatFlow {
+        ChatFlow {
+            tenant_id: tenant_id.to_string(),
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            enabled,
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+            nodes: vec![],
+            edges: vec![],
+            input_variables: vec![],
+            ai_tool: false,
+            ai_tool_description: String::new(),
+            max_steps: 0,
+            version: 1,
+        }
+    }
+
+    fn cursor(tenant_id: &str, session_id: &str, node_id: &str) -> FlowCursor {
+        FlowCursor {
+            tenant_id: tenant_id.to_string(),
+            session_id: session_id.to_string(),
+            flow_id: "flow-1".to_string(),
+            node_id: node_id.to_string(),
+            node_type: "buttons".to_string(),
+            variables: HashMap::from([("name".to_string(), "Ada".to_string())]),
+            created_at: "2026-01-01T00:00:00+00:00".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn session_tenant_reads_seeded_sessions() {
+        let store = MemoryStore::new();
+        store.insert_session("s1", "t1");
+        assert_eq!(store.session_tenant("s1").await.as_deref(), Some("t1"));
+        assert_eq!(store.session_tenant("missing").await, None);
+    }
+
+    #[tokio::test]
+    async fn tenant_flows_are_scoped_and_oldest_first() {
+        let store = MemoryStore::new();
+        store.insert_flow(flow("newer", "t1", true, "2026-02-01T00:00:00+00:00"));
+        store.insert_flow(flow("older", "t1", true, "2026-01-01T00:00:00+00:00"));
+        store.insert_flow(flow("other", "t2", true, "2025-01-01T00:00:00+00:00"));
+
+        let ids = store
+            .tenant_flows("t1")
+            .await
+            .into_iter()
+            .map(|flow| flow.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["older", "newer"]);
+        assert_eq!(
+            store.flow("other").await.map(|f| f.tenant_id).as_deref(),
+            Some("t2")
+        );
+    }
+
+    #[tokio::test]
+    async fn default_flow_is_oldest_enabled() {
+        let store = MemoryStore::new();
+        store.insert_flow(flow("disabled", "t1", false, "2026-01-01T00:00:00+00:00"));
+        store.insert_flow(flow("second", "t1", true, "2026-03-01T00:00:00+00:00"));
+        store.insert_flow(flow("first", "t1", true, "2026-02-01T00:00:00+00:00"));
+
+        assert_eq!(store.default_flow_id("t1").await.as_deref(), Some("first"));
+        assert_eq!(store.default_flow_id("t2").await, None);
+    }
+
+    #[tokio::test]
+    async fn flow_cursor_is_replaced_and_cleared_per_session() {
+        let store = MemoryStore::new();
+        store.save_flow_cursor(cursor("t1", "s1", "ask")).await;
+        store.save_flow_cursor(cursor("t1", "s1", "confirm")).await;
+        store.save_flow_cursor(cursor("t1", "s2", "ask")).await;
+
+        let saved = store.flow_cursor("t1", "s1").await.expect("cursor saved");
+        assert_eq!(saved.node_id, "confirm");
+        assert_eq!(saved.variables.get("name").map(String::as_str), Some("Ada"));
+        assert!(store.flow_cursor("t2", "s1").await.is_none());
+
+        store.clear_flow_cursor("t1", "s1").await;
+        assert!(store.flow_cursor("t1", "s1").await.is_none());
+        assert!(store.flow_cursor("t1", "s2").await.is_some());
+    }
+}
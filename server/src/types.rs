@@ -1,15 +1,24 @@
 use std::{
     collections::{HashMap, HashSet},
+    net::IpAddr,
     path::PathBuf,
-    sync::atomic::AtomicUsize,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize},
+        Arc,
+    },
 };
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::PgPool;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{mpsc, Mutex, Notify};
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::embeddings::Embedder;
+use crate::graphql::ApiSchema;
+use crate::store::Store;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChatMessage {
     pub id: String,
@@ -27,6 +36,12 @@ pub struct ChatMessage {
     pub agent_name: String,
     #[serde(default)]
     pub agent_avatar_url: String,
+    /// Visitor-side participant who wrote the message, in group chats and
+    /// CC'd email threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub participant_id: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub participant_name: String,
 }
 
 #[derive(Debug, Clone)]
@@ -47,7 +62,55 @@ pub struct Session {
     pub priority: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// Where the visitor is and who they are, as reported by the widget on
+/// `widget:join`. Used to target flow triggers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VisitorContext {
+    #[serde(default)]
+    pub page_url: String,
+    #[serde(default)]
+    pub referrer: String,
+    /// ISO country code, resolved from the GeoIP header set by the edge proxy.
+    #[serde(default)]
+    pub country: String,
+    #[serde(default)]
+    pub city: String,
+    /// IANA time zone, e.g. `Europe/Lisbon`.
+    #[serde(default)]
+    pub timezone: String,
+    /// `desktop`, `mobile` or `tablet`.
+    #[serde(default)]
+    pub device_type: String,
+    #[serde(default)]
+    pub returning: bool,
+    /// Custom attributes passed by the embed script.
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
+    /// When the visitor landed on `page_url`.
+    #[serde(default)]
+    pub viewed_at: String,
+    #[serde(default)]
+    pub client: ClientInfo,
+}
+
+/// Browser and device details of a widget visitor. Browser and OS are parsed
+/// server-side from the user agent; the rest is reported by the widget.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientInfo {
+    #[serde(default)]
+    pub browser: String,
+    #[serde(default)]
+    pub os: String,
+    /// Screen size as `<width>x<height>`.
+    #[serde(default)]
+    pub screen: String,
+    #[serde(default)]
+    pub language: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionSummary {
     pub tenant_id: String,
@@ -64,15 +127,68 @@ pub struct SessionSummary {
     pub contact_name: Option<String>,
     pub contact_email: Option<String>,
     pub contact_phone: Option<String>,
+    pub geo: Option<GeoLocation>,
+    pub client: Option<ClientInfo>,
     #[serde(default)]
     pub tags: Vec<SessionTagSummary>,
     pub visitor_id: String,
     pub handover_active: bool,
     pub status: String,
     pub priority: String,
+    pub archived_at: Option<String>,
+    pub deleted_at: Option<String>,
+    /// Set while the handover waits for agent capacity.
+    pub queued_at: Option<String>,
+    /// Skills auto-assignment prefers, e.g. `language:es` or `product:billing`.
+    #[serde(default)]
+    pub required_skills: Vec<String>,
+    /// The visitor's answer to the AI consent prompt; `None` until asked.
+    pub ai_consent: Option<AiConsent>,
+    /// Conversation on a channel in test mode.
+    #[serde(default)]
+    pub is_test: bool,
+    /// Short subject for the inbox, generated or typed by an agent; empty
+    /// until there is one.
+    #[serde(default)]
+    pub title: String,
+    /// Pinned to the top of the requesting agent's queue.
+    #[serde(default)]
+    pub pinned: bool,
+    /// Starred by the requesting agent.
+    #[serde(default)]
+    pub starred: bool,
+    /// Arrival of the visitor's oldest unanswered message while the session
+    /// is open; `None` once an agent or the bot has replied.
+    #[serde(default)]
+    pub waiting_since: Option<String>,
+}
+
+/// Visitor consent for AI processing, as recorded on the session.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AiConsent {
+    /// `granted` or `declined`.
+    pub status: String,
+    pub recorded_at: String,
+    /// Prompt wording the visitor answered.
+    pub prompt_text: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutAiConsentBody {
+    pub granted: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GeoLocation {
+    pub country: String,
+    pub city: String,
+    pub timezone: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionTagSummary {
     pub id: String,
@@ -80,7 +196,7 @@ pub struct SessionTagSummary {
     pub color: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CannedReply {
     pub tenant_id: String,
@@ -93,7 +209,66 @@ pub struct CannedReply {
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyTemplate {
+    pub tenant_id: String,
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    /// Team the template is shared with; `None` means the whole workspace.
+    pub team_id: Option<String>,
+    pub created_by: Option<String>,
+    pub usage_count: i64,
+    pub last_used_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Inbox filters saved in a view; unset fields do not filter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InboxViewFilters {
+    /// `mine`, `unassigned`, `mentions` or `all`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Status group as shown in the inbox, e.g. `active` or `resolved`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    /// Same as `archived` on `GET /api/sessions`.
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// A named inbox filter and sort. Private to its owner unless shared with
+/// one of their teams.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InboxView {
+    pub tenant_id: String,
+    pub id: String,
+    pub agent_id: String,
+    pub name: String,
+    pub filters: InboxViewFilters,
+    /// `priority` or `recent`, as on `GET /api/sessions`.
+    pub sort: String,
+    /// Team the view is shared with; `None` keeps it private.
+    pub team_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentProfile {
     pub id: String,
@@ -102,10 +277,14 @@ pub struct AgentProfile {
     pub status: String,
     pub role: String,
     pub avatar_url: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub signature: String,
     pub team_ids: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TenantInvitation {
     pub id: String,
@@ -119,7 +298,7 @@ pub struct TenantInvitation {
     pub expires_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TenantMember {
     pub id: String,
@@ -130,7 +309,7 @@ pub struct TenantMember {
     pub avatar_url: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Team {
     pub tenant_id: String,
@@ -139,7 +318,7 @@ pub struct Team {
     pub agent_ids: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Channel {
     pub id: String,
@@ -148,11 +327,71 @@ pub struct Channel {
     pub name: String,
     pub config: Value,
     pub enabled: bool,
+    /// Bot switch for this channel; `None` follows the workspace default.
+    pub bot_enabled: Option<bool>,
+    pub bot_quiet_hours: BotQuietHours,
+    /// Outbound messages go to the test capture log instead of the provider.
+    pub test_mode: bool,
     pub created_at: String,
     pub updated_at: String,
+    /// Bumped on every edit; clients echo it in `If-Match` when saving.
+    #[serde(default)]
+    pub version: i64,
+}
+
+/// Weekly windows during which the bot stays silent on a channel, evaluated
+/// at a fixed UTC offset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BotQuietHours {
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+    #[serde(default)]
+    pub windows: Vec<WeeklyWindow>,
+}
+
+/// A recurring weekly time range. `days` are `mon`..`sun`; `start`/`end` are
+/// `HH:MM`. A window whose end is before its start runs past midnight into the
+/// next day.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyWindow {
+    pub days: Vec<String>,
+    pub start: String,
+    pub end: String,
+}
+
+/// Structured record of something that happened to a conversation, such as
+/// an assignment, status, priority or tag change.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionEvent {
+    pub id: String,
+    pub tenant_id: String,
+    pub session_id: String,
+    /// `assigned`, `team_changed`, `status_changed`, `priority_changed`,
+    /// `snoozed`, `tag_added`, `tag_removed` or `transferred`.
+    pub kind: String,
+    /// `agent`, `bot`, `visitor` or `system`.
+    pub actor_type: String,
+    pub actor_id: Option<String>,
+    pub actor_name: String,
+    pub data: Value,
+    /// System message rendered for the event in the transcript, if any.
+    pub message_id: Option<String>,
+    pub created_at: String,
+}
+
+/// Transcript entry returned by the session timeline: a message or a
+/// structured event.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SessionTimelineItem {
+    Message { message: ChatMessage },
+    Event { event: SessionEvent },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationNote {
     pub tenant_id: String,
@@ -163,7 +402,7 @@ pub struct ConversationNote {
     pub created_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChatFlow {
     pub tenant_id: String,
@@ -181,9 +420,15 @@ pub struct ChatFlow {
     pub ai_tool: bool,
     #[serde(default)]
     pub ai_tool_description: String,
+    /// Most nodes one run may execute; 0 uses the server default.
+    #[serde(default)]
+    pub max_steps: i32,
+    /// Bumped on every edit; clients echo it in `If-Match` when saving.
+    #[serde(default)]
+    pub version: i64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FlowInputVariable {
     pub key: String,
@@ -191,9 +436,12 @@ pub struct FlowInputVariable {
     pub label: String,
     #[serde(default)]
     pub required: bool,
+    /// `string`, `number`, `boolean`, `datetime` or `list`; empty means string.
+    #[serde(default, rename = "type")]
+    pub var_type: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FlowNode {
     pub id: String,
@@ -205,7 +453,86 @@ pub struct FlowNode {
     pub data: Value,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Locales a flow has content variants for and what is left to translate.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowLocaleReport {
+    pub locales: Vec<String>,
+    pub missing: Vec<FlowMissingTranslation>,
+}
+
+/// A node whose visitor-facing copy has no variant for `locale`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowMissingTranslation {
+    pub locale: String,
+    pub node_id: String,
+    pub node_type: String,
+    pub fields: Vec<String>,
+}
+
+/// A flow node failure that had no `error` edge to recover through.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowError {
+    pub id: String,
+    pub flow_id: String,
+    pub session_id: String,
+    pub node_id: String,
+    pub node_type: String,
+    pub error: String,
+    pub payload: Value,
+    pub created_at: String,
+}
+
+/// One recorded step of a flow run.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowTraceStep {
+    pub seq: i32,
+    /// `start`, `node`, `ai_output`, `ai_decision`, `paused` or `error`.
+    pub kind: String,
+    pub node_id: String,
+    pub node_type: String,
+    /// Edge taken into this node from the previous one.
+    pub via_edge_id: Option<String>,
+    /// Flow variables on entering the node.
+    pub variables: Option<HashMap<String, String>>,
+    pub data: Value,
+    pub created_at: String,
+}
+
+/// Everything one flow run decided, in order. A resumed flow or a sub-flow
+/// is a run of its own.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowTraceRun {
+    pub run_id: String,
+    pub flow_id: String,
+    pub flow_name: String,
+    pub started_at: String,
+    pub steps: Vec<FlowTraceStep>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowNodeErrorCount {
+    pub node_id: String,
+    pub node_type: String,
+    pub count: i64,
+}
+
+/// Health of a flow: unhandled node failures, per node and most recent first.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowAnalytics {
+    pub flow_id: String,
+    pub error_count: i64,
+    pub errors_by_node: Vec<FlowNodeErrorCount>,
+    pub recent_errors: Vec<FlowError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FlowPosition {
     pub x: f64,
@@ -218,7 +545,7 @@ impl Default for FlowPosition {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FlowEdge {
     pub id: String,
@@ -232,15 +559,16 @@ pub struct FlowEdge {
     pub data: Value,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserProfile {
     pub id: String,
     pub email: String,
     pub full_name: String,
+    pub avatar_url: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkspaceSummary {
     pub id: String,
@@ -250,7 +578,23 @@ pub struct WorkspaceSummary {
     pub role: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Invitation addressed to the signed-in user's email that is still open.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingInvitation {
+    pub id: String,
+    pub tenant_id: String,
+    pub workspace_name: String,
+    pub workspace_username: String,
+    pub role: String,
+    /// Pass to `POST /api/invitations/accept`.
+    pub token: String,
+    pub invited_by_name: String,
+    pub created_at: String,
+    pub expires_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Tenant {
     pub id: String,
@@ -261,7 +605,7 @@ pub struct Tenant {
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TenantSettings {
     pub tenant_id: String,
@@ -278,11 +622,88 @@ pub struct TenantSettings {
     pub bot_avatar_url: String,
     pub bot_enabled_by_default: bool,
     pub bot_personality: String,
+    /// IANA zone for flows when the visitor's zone is unknown.
+    pub default_timezone: String,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Bumped on every edit; clients echo it in `If-Match` when saving.
+    #[serde(default)]
+    pub version: i64,
+}
+
+/// Per-workspace data retention. A retention of `0` days keeps data forever.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPolicy {
+    pub tenant_id: String,
+    pub message_retention_days: i32,
+    pub note_retention_days: i32,
+    pub media_retention_days: i32,
+    /// `anonymize` blanks old content but keeps the rows; `delete` removes them.
+    pub mode: String,
+    pub last_run_at: Option<String>,
+    pub updated_at: String,
+}
+
+/// Preferred translation of a term, pinned by the workspace. An empty
+/// `source_lang` applies to any source language.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GlossaryTerm {
+    pub tenant_id: String,
+    pub id: String,
+    pub source_lang: String,
+    pub target_lang: String,
+    pub term: String,
+    pub translation: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Proactive message pushed to widget visitors who match the targeting rules
+/// and stay on the page for `delay_seconds`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Campaign {
+    pub tenant_id: String,
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub message: String,
+    /// Same shape as flow trigger targeting rules.
+    pub rules: Vec<Value>,
+    pub logic_operator: String,
+    pub delay_seconds: i32,
+    /// Frequency cap: deliveries per visitor within `cap_window_hours`
+    /// (0 = lifetime).
+    pub max_per_visitor: i32,
+    pub cap_window_hours: i32,
     pub created_at: String,
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignStats {
+    pub sent: i64,
+    /// Deliveries the visitor replied to.
+    pub converted: i64,
+}
+
+/// What a retention run would touch right now.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPreview {
+    pub mode: String,
+    pub message_cutoff: Option<String>,
+    pub note_cutoff: Option<String>,
+    pub media_cutoff: Option<String>,
+    pub messages: i64,
+    pub notes: i64,
+    pub media_files: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Contact {
     pub id: String,
@@ -298,11 +719,16 @@ pub struct Contact {
     pub last_seen_at: String,
     pub browser: String,
     pub os: String,
+    pub screen_size: String,
+    pub language: String,
+    pub country: String,
+    pub city: String,
+    pub timezone: String,
     pub created_at: String,
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Tag {
     pub id: String,
@@ -314,7 +740,7 @@ pub struct Tag {
     pub created_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KbCollection {
     pub id: String,
@@ -325,7 +751,7 @@ pub struct KbCollection {
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KbArticle {
     pub id: String,
@@ -336,12 +762,14 @@ pub struct KbArticle {
     pub markdown: String,
     pub plain_text: String,
     pub status: String,
+    /// Language the article is written in; AI answers translate from it.
+    pub language: String,
     pub created_at: String,
     pub updated_at: String,
     pub published_at: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KbChunk {
     pub id: String,
@@ -353,7 +781,7 @@ pub struct KbChunk {
     pub created_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KbTag {
     pub id: String,
@@ -364,7 +792,7 @@ pub struct KbTag {
     pub created_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KbSearchHit {
     pub article_id: String,
@@ -381,7 +809,104 @@ pub struct KbSearchHit {
     pub tags: Vec<KbTag>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct KbAnalyticsQuery {
+    /// Days back to look, 1 to 365; defaults to 30.
+    pub days: Option<i64>,
+}
+
+/// How often an article grounded AI answers and how those chats ended.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KbArticleUsage {
+    pub article_id: String,
+    pub title: String,
+    pub retrievals: i64,
+    pub conversations: i64,
+    /// Conversations handed to a human after the article was retrieved.
+    pub escalated: i64,
+    pub escalation_rate: f64,
+    pub last_retrieved_at: String,
+    pub updated_at: String,
+    /// Still in use but not edited for `KB_STALE_DAYS`.
+    pub stale: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KbArticleRef {
+    pub article_id: String,
+    pub title: String,
+    pub updated_at: String,
+}
+
+/// Similar visitor questions the KB had no good match for.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KbQuestionCluster {
+    pub question: String,
+    pub count: i64,
+    pub examples: Vec<String>,
+    pub last_asked_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KbAnalytics {
+    pub days: i64,
+    pub top_articles: Vec<KbArticleUsage>,
+    /// Published articles no AI answer retrieved in the period.
+    pub never_retrieved: Vec<KbArticleRef>,
+    /// Candidate new articles, largest cluster first.
+    pub gaps: Vec<KbQuestionCluster>,
+}
+
+/// Visitor questions the bot struggled with, grouped by meaning.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionCluster {
+    pub id: String,
+    /// The question closest to the middle of the cluster.
+    pub representative: String,
+    pub size: i32,
+    /// Questions followed by a handover to a human.
+    pub escalated: i32,
+    /// Questions the KB had no good match for.
+    pub low_confidence: i32,
+    pub examples: Vec<String>,
+    pub first_seen_at: String,
+    pub last_seen_at: String,
+    pub computed_at: String,
+}
+
+/// One chunk from hybrid KB search with every score component, for tuning
+/// retrieval. Ranks are 0-based; `None` means that retriever missed it.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KbSearchDebugHit {
+    pub chunk_id: String,
+    pub chunk_index: i32,
+    pub article_id: String,
+    pub article_title: String,
+    pub collection_name: String,
+    pub snippet: String,
+    pub vector_rank: Option<usize>,
+    /// Cosine similarity to the query embedding.
+    pub vector_score: f64,
+    pub keyword_rank: Option<usize>,
+    /// Postgres full-text `ts_rank_cd`.
+    pub keyword_score: f64,
+    pub exact_rank: Option<usize>,
+    /// How many code-like query terms appear literally in the chunk.
+    pub exact_matches: i32,
+    /// Reciprocal-rank fusion of the three ranks.
+    pub fused_score: f64,
+    pub rerank_score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ContactAttribute {
     pub id: String,
@@ -392,7 +917,92 @@ pub struct ContactAttribute {
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Internal note on a contact, kept across all their conversations.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactNote {
+    pub tenant_id: String,
+    pub id: String,
+    pub contact_id: String,
+    pub agent_id: String,
+    pub text: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// A person on the visitor side of a conversation with more than one, such
+/// as a WhatsApp group member or a colleague CC'd on an email thread.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionParticipant {
+    pub id: String,
+    pub session_id: String,
+    /// `email:<address>` or `whatsapp:<digits>`.
+    pub handle: String,
+    pub display_name: String,
+    pub contact_id: Option<String>,
+    /// `primary`, `member` or `cc`.
+    pub role: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSessionParticipantBody {
+    /// Email address or phone number.
+    pub address: String,
+    #[serde(default)]
+    pub display_name: String,
+}
+
+/// Entry in a contact's activity feed.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactActivity {
+    pub id: String,
+    pub contact_id: String,
+    /// `session_opened`, `session_joined`, `campaign_received`,
+    /// `attribute_changed` or `note_added`.
+    pub kind: String,
+    /// `agent`, `bot`, `visitor` or `crm`.
+    pub actor_type: String,
+    pub actor_id: Option<String>,
+    pub session_id: Option<String>,
+    pub data: Value,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct ContactActivityQuery {
+    /// Only entries older than this RFC3339 time, for paging.
+    pub before: Option<String>,
+    /// 1 to 200; defaults to 50.
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct MessageContextQuery {
+    /// Messages to include on each side of the target, 1 to 100; defaults
+    /// to 20.
+    pub around: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct MessageAtQuery {
+    /// RFC 3339 timestamp; the message stored closest to it is the target.
+    pub at: String,
+    /// Messages to include on each side of the target, 1 to 100; defaults
+    /// to 20.
+    pub around: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationAttribute {
     pub id: String,
@@ -403,7 +1013,7 @@ pub struct ConversationAttribute {
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CsatSurvey {
     pub id: String,
@@ -412,9 +1022,12 @@ pub struct CsatSurvey {
     pub score: i32,
     pub comment: String,
     pub submitted_at: String,
+    /// Agent who resolved the conversation, for surveys sent after an agent
+    /// closed it.
+    pub agent_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentNotification {
     pub id: String,
@@ -429,18 +1042,160 @@ pub struct AgentNotification {
     pub created_at: String,
 }
 
-#[derive(Default)]
-pub struct RealtimeState {
-    pub clients: HashMap<usize, mpsc::UnboundedSender<String>>,
-    pub agents: HashSet<usize>,
-    pub agent_profiles: HashMap<usize, AgentProfile>,
-    pub agent_tenant_by_client: HashMap<usize, String>,
-    pub session_watchers: HashMap<String, HashSet<usize>>,
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestPreference {
+    /// `daily`, `weekly` or `off`.
+    pub frequency: String,
+    pub last_sent_at: String,
+    /// Whether this server can send email at all.
+    pub email_configured: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutDigestPreferenceBody {
+    pub frequency: String,
+}
+
+/// A workspace's warehouse export. Credential values are never returned.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WarehouseExport {
+    /// `s3` or `bigquery`.
+    pub destination: String,
+    pub configured_fields: Vec<String>,
+    pub enabled: bool,
+    /// Tables are named `{table}_v{schemaVersion}`.
+    pub schema_version: i64,
+    pub last_run_at: String,
+    /// `ok`, `failed` or empty before the first run.
+    pub last_status: String,
+    pub last_error: String,
+    /// Rows exported by the last run.
+    pub last_row_count: i64,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutWarehouseExportBody {
+    pub destination: String,
+    pub enabled: Option<bool>,
+    /// Merged into the stored credentials when the destination is unchanged;
+    /// empty values keep what is stored.
+    #[serde(default)]
+    pub credentials: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WarehouseBackfillBody {
+    /// RFC 3339 start; everything is exported again when omitted.
+    pub since: Option<String>,
+}
+
+/// Endpoint receiving the workspace's realtime events as signed POSTs.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventWebhook {
+    pub url: String,
+    /// HMAC-SHA256 key for the `X-Chat-Signature` header.
+    pub secret: String,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutEventWebhookBody {
+    pub url: String,
+    pub enabled: Option<bool>,
+    /// Issue a new signing secret; the old one stops working at once.
+    #[serde(default)]
+    pub rotate_secret: bool,
+}
+
+/// A workspace's wording of one system message in one locale.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemMessageTemplate {
+    pub key: String,
+    pub locale: String,
+    /// Text with `{{variable}}` placeholders, e.g. `{{agent.name}}`.
+    pub template: String,
+    pub updated_at: String,
+}
+
+/// A system message's built-in English copy and the variables it accepts.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemMessageDefault {
+    pub key: String,
+    pub template: String,
+    pub variables: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutSystemMessageTemplateBody {
+    pub template: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreference {
+    pub kind: String,
+    /// `in_app` (stored in the notification feed) or `realtime` (live toast).
+    pub channel: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutNotificationPreferencesBody {
+    pub preferences: Vec<NotificationPreference>,
+}
+
+/// Protocol version and capabilities a WebSocket client declared in `hello`.
+#[derive(Debug, Clone)]
+pub struct ClientProtocol {
+    pub version: u32,
+    pub capabilities: HashSet<String>,
+}
+
+#[derive(Default)]
+pub struct RealtimeState {
+    pub clients: HashMap<usize, mpsc::UnboundedSender<String>>,
+    pub client_protocols: HashMap<usize, ClientProtocol>,
+    /// Key each SSE client presents when posting its events.
+    pub sse_client_keys: HashMap<usize, String>,
+    pub widget_session_by_client: HashMap<usize, String>,
+    pub agents: HashSet<usize>,
+    pub agent_profiles: HashMap<usize, AgentProfile>,
+    pub agent_tenant_by_client: HashMap<usize, String>,
+    /// Platform admin behind each agent client joined with an impersonation
+    /// token.
+    pub agent_impersonators: HashMap<usize, String>,
+    pub session_watchers: HashMap<String, HashSet<usize>>,
     pub watched_session: HashMap<usize, String>,
     pub agent_auto_typing_counts: HashMap<String, usize>,
     pub agent_human_typers: HashMap<String, HashSet<usize>>,
     pub agent_human_typing_session: HashMap<usize, String>,
     pub visitor_typing_session: HashMap<usize, String>,
+    /// Owner/admin clients silently monitoring each session.
+    pub session_monitors: HashMap<String, HashSet<usize>>,
+    /// Waiting thresholds already announced for each session still waiting.
+    pub session_waiting_levels: HashMap<String, usize>,
+    /// Visitor message fragments held for the burst window, by session, with
+    /// a counter bumped by each new fragment.
+    pub visitor_bursts: HashMap<String, (u64, Vec<String>)>,
+    /// Per-session turn lock so flow/AI runs execute one at a time, with a
+    /// counter bumped by each visitor message to spot superseded runs.
+    pub session_runs: HashMap<String, (u64, Arc<Mutex<()>>)>,
+    /// Cancellation flag of the turn currently running for each session.
+    pub flow_runs: HashMap<String, Arc<AtomicBool>>,
 }
 
 pub struct AppState {
@@ -450,16 +1205,340 @@ pub struct AppState {
     pub ai_client: reqwest::Client,
     pub media_storage_dir: PathBuf,
     pub public_base_url: String,
+    pub widget_session_secret: String,
+    pub widget_session_token_ttl_secs: i64,
+    pub widget_session_token_required: bool,
+    /// MaxMind-format city database used to enrich widget visitors.
+    pub geoip: Option<maxminddb::Reader<Vec<u8>>>,
+    /// Platform operator API tokens as `(operator, token)`; the operator
+    /// names the caller in the audit log. Empty disables the API.
+    pub admin_api_tokens: Vec<(String, String)>,
+    /// Feature flag overrides by scope (`""` is global), loaded on demand and
+    /// dropped when another instance reports a change.
+    pub feature_flags: Mutex<HashMap<String, HashMap<String, bool>>>,
+    /// Signing secret for Stripe webhooks; empty disables the endpoint.
+    pub stripe_webhook_secret: String,
+    /// Malware scanner for stored media; `None` serves files unscanned.
+    pub media_scanner: Option<MediaScanner>,
+    /// Default lifetime of signed media URLs; workspaces may override it.
+    pub media_url_ttl_secs: i64,
+    /// When false, unsigned `/api/media` requests are still served (rollout
+    /// window for clients that cached bare URLs).
+    pub media_signed_urls_required: bool,
+    /// Command that converts HEIC/HEIF images to JPEG (`<cmd> <in> <out>`);
+    /// `None` leaves HEIC attachments without web renditions.
+    pub heic_converter: Option<String>,
+    /// Key wrapping for at-rest encryption; `None` means no workspace can
+    /// turn encryption on.
+    pub encryption: Option<EncryptionConfig>,
+    /// Unwrapped data keys by id, so reads do not hit the KMS every time.
+    pub data_keys: Mutex<HashMap<String, Vec<u8>>>,
+    /// Host that tenant custom domains must CNAME to.
+    pub custom_domain_target: String,
+    /// Called when a custom domain is verified or removed so the edge can
+    /// issue or drop its certificate; `None` relies on on-demand TLS asking
+    /// `/api/domains/tls-ask`.
+    pub custom_domain_cert_hook: Option<String>,
+    /// DNS-over-HTTPS JSON endpoint used to check domain records.
+    pub dns_over_https_url: String,
+    /// Request host to verified tenant, with when it was looked up.
+    pub domain_tenants: Mutex<HashMap<String, (Option<String>, std::time::Instant)>>,
+    /// Origins from `CORS_ALLOWED_ORIGINS`, trusted for the dashboard and
+    /// the widget alike.
+    pub cors_origins: HashSet<String>,
+    /// Addresses and CIDR ranges from `TRUSTED_PROXIES` whose
+    /// `X-Forwarded-For`, `X-Real-IP` and country headers are believed.
+    pub trusted_proxies: Vec<(IpAddr, u8)>,
+    /// Whether a request origin is some workspace's widget origin, with when
+    /// it was looked up.
+    pub widget_origin_cache: Mutex<HashMap<String, (bool, std::time::Instant)>>,
+    /// Failed widget bootstraps per client address, with when the window
+    /// started.
+    pub widget_bootstrap_failures: Mutex<HashMap<IpAddr, (u32, std::time::Instant)>>,
+    /// Calendar OAuth clients by provider (`google`, `microsoft`); providers
+    /// without one cannot be connected.
+    pub calendar_apps: HashMap<String, CalendarOAuthApp>,
+    /// Parent domain of forwarding addresses (`support@<workspace>.<domain>`);
+    /// empty disables inbound email.
+    pub inbound_email_domain: String,
+    /// Mailgun webhook signing key; empty disables the Mailgun endpoint.
+    pub mailgun_signing_key: String,
+    /// Token SNS deliveries of SES mail must carry; empty disables the SES
+    /// endpoint.
+    pub ses_inbound_token: String,
+    /// Embedder for KB chunks and search queries.
+    pub embedder: Box<dyn Embedder>,
+    /// Flow engine persistence; Postgres unless running in memory.
+    pub store: Arc<dyn Store>,
+    /// Mail the server sends itself, such as digests; `None` when
+    /// `MAILGUN_API_KEY` is not set.
+    pub outbound_email: Option<OutboundEmail>,
+    /// Workspaces whose warehouse export is running on this instance.
+    pub warehouse_exports_running: Mutex<HashSet<String>>,
+    /// Wakes the outbox dispatcher after a transaction queued events.
+    pub outbox_wake: Notify,
+    /// Wakes the event webhook worker after the dispatcher queued deliveries.
+    pub event_webhook_wake: Notify,
+    /// Schema behind `/api/graphql`; request data carries the state.
+    pub graphql: ApiSchema,
+}
+
+/// Mailgun HTTP API settings for outbound mail.
+#[derive(Debug, Clone)]
+pub struct OutboundEmail {
+    pub api_base: String,
+    pub domain: String,
+    pub api_key: String,
+    pub from: String,
 }
 
-#[derive(Debug, Deserialize)]
+/// Tenant resolved from the request `Host` of a verified custom domain.
+#[derive(Debug, Clone)]
+pub struct HostTenant(pub String);
+
+#[derive(Debug, Clone)]
+pub struct CalendarOAuthApp {
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+/// How per-workspace data keys are wrapped.
+#[derive(Debug, Clone)]
+pub struct EncryptionConfig {
+    /// 32-byte local master keys, current first. Older keys only unwrap, so a
+    /// master key can be replaced and data keys rewrapped afterwards.
+    pub master_keys: Vec<Vec<u8>>,
+    /// When set, new data keys are wrapped by the Vault transit engine
+    /// instead of the local master key.
+    pub vault: Option<VaultTransit>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VaultTransit {
+    pub url: String,
+    pub token: String,
+    pub key_name: String,
+}
+
+/// Where stored media is sent for malware scanning.
+#[derive(Debug, Clone)]
+pub enum MediaScanner {
+    /// clamd `INSTREAM` over TCP (`host:port`) or a unix socket path.
+    ClamAv { address: String },
+    /// External API that receives the raw bytes and answers
+    /// `{"infected": bool, "signature": "..."}`.
+    Http { url: String, api_key: String },
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DataKeyInfo {
+    pub id: String,
+    /// `local` or `vault`.
+    pub provider: String,
+    /// `active` or `retired`; retired keys still decrypt.
+    pub status: String,
+    pub created_at: String,
+    pub retired_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionSettings {
+    pub enabled: bool,
+    /// False when the server has no master key or KMS configured.
+    pub available: bool,
+    pub active_key_id: Option<String>,
+    pub keys: Vec<DataKeyInfo>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchEncryptionBody {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RotateEncryptionKeyBody {
+    /// Re-encrypt existing messages and files under the new key in the
+    /// background, including ones stored before encryption was enabled.
+    #[serde(default)]
+    pub reencrypt: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaSettings {
+    /// Lifetime of signed media URLs issued for this workspace.
+    pub url_ttl_secs: i64,
+    /// True when the workspace inherits the server default TTL.
+    pub uses_default_ttl: bool,
+    pub default_url_ttl_secs: i64,
+    pub signed_urls_required: bool,
+    /// Last change to the TTL or the signing key.
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchMediaSettingsBody {
+    /// `0` resets to the server default.
+    pub url_ttl_secs: Option<i64>,
+    /// Replace the signing key, invalidating every outstanding media link.
+    pub rotate_key: Option<bool>,
+}
+
+/// Effective value of a feature flag for a workspace. Tenant overrides win
+/// over global ones, which win over the built-in default.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureFlag {
+    pub key: String,
+    pub description: String,
+    pub enabled: bool,
+    pub default: bool,
+    pub global: Option<bool>,
+    pub tenant: Option<bool>,
+}
+
+/// Flag overrides to apply; `null` clears an override.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateFeatureFlagsBody {
+    /// Platform API only: workspace to change instead of the global values.
+    #[serde(default)]
+    pub tenant_id: Option<String>,
+    pub flags: HashMap<String, Option<bool>>,
+}
+
+/// Workspace as seen by platform admins, with usage counters.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminTenantSummary {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub workspace_username: String,
+    pub created_at: String,
+    pub suspended_at: String,
+    pub suspended_reason: String,
+    pub agents: i64,
+    pub channels: i64,
+    pub contacts: i64,
+    pub conversations: i64,
+    pub conversations_30d: i64,
+    pub messages_30d: i64,
+    pub last_activity_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminAuditEntry {
+    pub id: String,
+    pub actor: String,
+    pub action: String,
+    pub tenant_id: String,
+    pub detail: Value,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryAttempt {
+    pub id: String,
+    pub tenant_id: String,
+    pub session_id: String,
+    pub channel: String,
+    pub kind: String,
+    pub ok: bool,
+    pub error: String,
+    pub created_at: String,
+}
+
+/// Delivery outcomes for one channel and kind over the dashboard window.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryStat {
+    pub channel: String,
+    pub kind: String,
+    pub total: i64,
+    pub failed: i64,
+}
+
+/// Quotas of a billing plan; `None` means unlimited.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanLimits {
+    pub agents: Option<i64>,
+    pub conversations_per_month: Option<i64>,
+    pub ai_calls_per_month: Option<i64>,
+    pub channels: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageCounts {
+    pub agents: i64,
+    pub conversations: i64,
+    pub ai_calls: i64,
+    pub channels: i64,
+    /// Prompt and completion tokens of this month's AI calls.
+    pub ai_prompt_tokens: i64,
+    pub ai_completion_tokens: i64,
+}
+
+/// Current plan of a workspace with this month's usage against its quotas.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BillingUsage {
+    pub plan: String,
+    pub status: String,
+    pub current_period_end: String,
+    /// Calendar month (UTC) the monthly counters cover, as `YYYY-MM`.
+    pub period: String,
+    pub limits: PlanLimits,
+    pub usage: UsageCounts,
+}
+
+/// Why a platform admin is suspending or entering a workspace; kept in the audit log.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminReasonBody {
+    #[serde(default)]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateBody {
+    pub text: String,
+    #[serde(default)]
+    pub source_lang: Option<String>,
+    pub target_lang: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateGlossaryTermBody {
+    #[serde(default)]
+    pub source_lang: Option<String>,
+    pub target_lang: String,
+    pub term: String,
+    pub translation: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SendMessageBody {
     pub sender: Option<String>,
     pub text: String,
+    /// Send an agent reply even though a teammate just answered the visitor.
+    #[serde(default)]
+    pub force: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RegisterBody {
     pub name: String,
@@ -471,7 +1550,7 @@ pub struct RegisterBody {
     pub invitation_token: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SignupBody {
     pub full_name: String,
@@ -479,14 +1558,33 @@ pub struct SignupBody {
     pub password: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SelectWorkspaceBody {
     pub login_ticket: String,
     pub workspace_username: String,
+    /// Issue the token as a cookie session, as in [`LoginBody`].
+    #[serde(default)]
+    pub cookie_session: bool,
 }
 
-#[derive(Debug, Deserialize)]
+/// Target workspace by id or username; the caller is identified by the
+/// login ticket or, without one, by the bearer token.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchWorkspaceBody {
+    #[serde(default)]
+    pub login_ticket: Option<String>,
+    #[serde(default)]
+    pub workspace_id: Option<String>,
+    #[serde(default)]
+    pub workspace_username: Option<String>,
+    /// Issue the token as a cookie session, as in [`LoginBody`].
+    #[serde(default)]
+    pub cookie_session: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AcceptInvitationBody {
     #[serde(default)]
@@ -494,7 +1592,7 @@ pub struct AcceptInvitationBody {
     pub invitation_token: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InviteMemberBody {
     pub email: String,
@@ -506,137 +1604,973 @@ fn default_member_role() -> String {
     "agent".to_string()
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateMemberRoleBody {
     pub role: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct LoginBody {
     pub email: String,
     pub password: String,
+    /// Set the token as an HttpOnly cookie instead of returning it; the
+    /// response carries the CSRF token that writes must send back.
+    #[serde(default)]
+    pub cookie_session: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StatusBody {
     pub status: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PatchAgentProfileBody {
     pub name: Option<String>,
     pub avatar_url: Option<String>,
+    pub title: Option<String>,
+    pub signature: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTeamBody {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateChannelBody {
+    pub channel_type: String,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub config: Option<Value>,
+    /// `on`, `off` or `default` (follow the workspace setting).
+    pub bot_mode: Option<String>,
+    pub bot_quiet_hours: Option<BotQuietHours>,
+    pub test_mode: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChannelBody {
+    pub channel_type: Option<String>,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub config: Option<Value>,
+    pub enabled: Option<bool>,
+    /// `on`, `off` or `default` (follow the workspace setting).
+    pub bot_mode: Option<String>,
+    pub bot_quiet_hours: Option<BotQuietHours>,
+    pub test_mode: Option<bool>,
+}
+
+/// An outbound message a test-mode channel captured instead of sending.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelTestCapture {
+    pub id: String,
+    pub channel_id: String,
+    pub session_id: String,
+    /// `message` or `template`.
+    pub kind: String,
+    /// Request body the provider would have received.
+    pub payload: Value,
+    /// What the customer would have seen.
+    pub rendered_text: String,
+    pub created_at: String,
+}
+
+/// A customer message injected into a test-mode channel.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateInboundBody {
+    pub phone: String,
+    pub text: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+/// A workspace ban on one visitor identity.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VisitorBan {
+    pub id: String,
+    /// `visitor_id`, `email`, `phone` or `ip`.
+    pub kind: String,
+    pub value: String,
+    pub reason: String,
+    /// Empty for a permanent ban.
+    pub expires_at: String,
+    pub created_by: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateVisitorBanBody {
+    pub kind: String,
+    pub value: String,
+    #[serde(default)]
+    pub reason: String,
+    /// RFC 3339 timestamp; omit for a permanent ban.
+    pub expires_at: Option<String>,
+}
+
+/// Ban every identity known for a conversation's visitor.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BanSessionVisitorBody {
+    #[serde(default)]
+    pub reason: String,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignBody {
+    pub agent_id: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionAssigneeBody {
+    pub agent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionChannelBody {
+    pub channel: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionTeamBody {
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteBody {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionFlowBody {
+    pub flow_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionHandoverBody {
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SendWhatsappTemplateBody {
+    pub template_name: String,
+    pub language_code: Option<String>,
+    #[serde(default)]
+    pub parameters: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTenantBody {
+    pub name: String,
+    #[serde(default)]
+    pub workspace_username: Option<String>,
+    #[serde(default)]
+    pub login_ticket: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchTenantSettingsBody {
+    pub brand_name: Option<String>,
+    pub workspace_short_bio: Option<String>,
+    pub workspace_description: Option<String>,
+    pub primary_color: Option<String>,
+    pub accent_color: Option<String>,
+    pub logo_url: Option<String>,
+    pub privacy_url: Option<String>,
+    pub launcher_position: Option<String>,
+    pub welcome_text: Option<String>,
+    pub bot_name: Option<String>,
+    pub bot_avatar_url: Option<String>,
+    pub bot_enabled_by_default: Option<bool>,
+    pub bot_personality: Option<String>,
+    pub default_timezone: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCampaignBody {
+    pub name: String,
+    pub message: String,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    #[serde(default)]
+    pub rules: Vec<Value>,
+    #[serde(default)]
+    pub logic_operator: Option<String>,
+    #[serde(default)]
+    pub delay_seconds: Option<i32>,
+    #[serde(default)]
+    pub max_per_visitor: Option<i32>,
+    #[serde(default)]
+    pub cap_window_hours: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCampaignBody {
+    pub name: Option<String>,
+    pub message: Option<String>,
+    pub enabled: Option<bool>,
+    pub rules: Option<Vec<Value>>,
+    pub logic_operator: Option<String>,
+    pub delay_seconds: Option<i32>,
+    pub max_per_visitor: Option<i32>,
+    pub cap_window_hours: Option<i32>,
+}
+
+/// Handover capacity of a workspace. A limit of `0` means uncapped.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueSettings {
+    pub tenant_id: String,
+    /// Open handovers each online agent can hold at once.
+    pub max_per_agent: i32,
+    /// Open handovers per team id, overriding the per-agent total for that team.
+    pub team_limits: HashMap<String, i32>,
+    /// `keep_bot`, `collect_email` or `backup_team`.
+    pub overflow_behavior: String,
+    pub backup_team_id: Option<String>,
+    /// Sent to the visitor when their handover is queued; empty uses a default.
+    pub overflow_message: String,
+    /// Assign handed-over sessions to an online agent automatically.
+    pub auto_assign: bool,
+    /// Seconds after an agent's reply during which a teammate's reply to the
+    /// same visitor needs confirming; `0` turns the check off.
+    pub reply_collision_window_secs: i32,
+    /// Minutes of waiting for a reply at which `session:waiting` fires,
+    /// ascending.
+    pub waiting_alert_mins: Vec<i32>,
+    /// Team id per primary language code; new conversations whose first
+    /// message is detected in that language are routed there.
+    pub language_teams: HashMap<String, String>,
+    pub updated_at: String,
+}
+
+/// PII masking applied to visitor messages before they are stored, sent to
+/// AI providers or passed to flows.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PiiSettings {
+    pub tenant_id: String,
+    pub enabled: bool,
+    /// Payment card numbers (Luhn-checked).
+    pub detect_cards: bool,
+    /// US SSNs, Brazilian CPFs and UK National Insurance numbers.
+    pub detect_national_ids: bool,
+    /// Values following "password", "senha", "pwd" and similar keywords.
+    pub detect_passwords: bool,
+    pub custom_patterns: Vec<PiiPattern>,
+    pub updated_at: String,
+}
+
+/// Extra pattern masked as `[redacted:{name}]`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PiiPattern {
+    pub name: String,
+    /// Rust `regex` syntax.
+    pub pattern: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchPiiSettingsBody {
+    pub enabled: Option<bool>,
+    pub detect_cards: Option<bool>,
+    pub detect_national_ids: Option<bool>,
+    pub detect_passwords: Option<bool>,
+    /// Replaces the whole list.
+    pub custom_patterns: Option<Vec<PiiPattern>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PiiPreviewBody {
+    pub text: String,
+}
+
+/// Network rules checked when a widget visitor opens or joins a conversation.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkRules {
+    pub tenant_id: String,
+    pub enabled: bool,
+    /// IPs or CIDR ranges that are always refused.
+    pub deny_list: Vec<String>,
+    /// When not empty, only these IPs or CIDR ranges are let in.
+    pub allow_list: Vec<String>,
+    /// ISO 3166-1 alpha-2 codes refused by GeoIP country.
+    pub blocked_countries: Vec<String>,
+    /// Shown in the widget to refused visitors.
+    pub denial_message: String,
+    /// Sites embedding the widget, allowed through CORS whether or not the
+    /// rules are enabled.
+    pub widget_origins: Vec<String>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchNetworkRulesBody {
+    pub enabled: Option<bool>,
+    /// Each list replaces the stored one.
+    pub deny_list: Option<Vec<String>>,
+    pub allow_list: Option<Vec<String>>,
+    pub blocked_countries: Option<Vec<String>>,
+    pub denial_message: Option<String>,
+    pub widget_origins: Option<Vec<String>>,
+}
+
+/// Everything the widget needs to start, in one call.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetBootstrapBody {
+    pub workspace_username: String,
+    /// HS256 JWT from the embedding site, signed with the workspace's widget
+    /// identity secret, naming its signed-in user.
+    #[serde(default)]
+    pub identity_token: Option<String>,
+    /// Anonymous id the widget keeps; ignored for identified visitors.
+    /// Ids in the `identity:` namespace are rejected without a verified
+    /// identity token.
+    #[serde(default)]
+    pub visitor_id: String,
+    /// Conversation the anonymous widget wants to resume; only honoured
+    /// with its `X-Session-Token`.
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+/// Claims of a widget identity token; `sub` is the site's user id.
+#[derive(Debug, Deserialize)]
+pub struct WidgetIdentityClaims {
+    pub sub: String,
+    pub exp: i64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub phone: String,
+}
+
+/// A customer hostname serving the widget, media and webhooks for a workspace.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomDomain {
+    pub id: String,
+    pub tenant_id: String,
+    pub hostname: String,
+    /// `pending`, `verified` or `failed`.
+    pub status: String,
+    /// `none`, `on_demand`, `requested` or `failed`.
+    pub cert_status: String,
+    pub verification_token: String,
+    pub verified_at: Option<String>,
+    pub last_checked_at: Option<String>,
+    pub last_error: String,
+    pub created_at: String,
+    /// Records the customer has to create at their DNS provider.
+    pub dns_records: Vec<DnsRecordHint>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsRecordHint {
+    /// `CNAME` or `TXT`.
+    pub record_type: String,
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomDomainBody {
+    pub hostname: String,
+}
+
+/// A routing skill such as `language:pt` or `product:billing`, rated 1-5.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSkill {
+    pub skill: String,
+    pub proficiency: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutAgentSkillsBody {
+    pub skills: Vec<AgentSkill>,
+}
+
+/// Weekly working hours of an agent. Without windows the agent counts as
+/// always scheduled.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSchedule {
+    pub agent_id: String,
+    pub utc_offset_minutes: i32,
+    pub windows: Vec<WeeklyWindow>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutAgentScheduleBody {
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+    #[serde(default)]
+    pub windows: Vec<WeeklyWindow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentTimeOff {
+    pub id: String,
+    pub agent_id: String,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub reason: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTimeOffBody {
+    pub starts_at: String,
+    pub ends_at: String,
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// Scheduled versus actual online time of one agent over a report range.
+/// Scheduled figures are `None` for agents without a schedule.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentAvailabilityStat {
+    pub agent_id: String,
+    pub agent_name: String,
+    pub scheduled_seconds: Option<i64>,
+    pub time_off_seconds: i64,
+    pub online_seconds: i64,
+    pub online_in_schedule_seconds: Option<i64>,
+}
+
+/// A private note from a supervisor to the agent handling a conversation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SupervisorWhisper {
+    pub id: String,
+    pub session_id: String,
+    pub supervisor_id: String,
+    pub supervisor_name: String,
+    /// The agent the whisper is for.
+    pub agent_id: String,
+    pub text: String,
+    pub created_at: String,
+}
+
+/// One supervisor action: `monitor_started`, `monitor_stopped`, `whisper`,
+/// `barge_in` or `takeover`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SupervisorAuditEntry {
+    pub id: String,
+    pub session_id: String,
+    pub supervisor_id: Option<String>,
+    pub supervisor_name: String,
+    pub action: String,
+    /// The agent handling the conversation at the time.
+    pub agent_id: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct SupervisorAuditQuery {
+    pub session_id: Option<String>,
+    /// Entries to return, newest first; 1 to 500, defaults to 100.
+    pub limit: Option<i64>,
+}
+
+/// A supervisor's QA note on a range of messages in a conversation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QaHighlight {
+    pub id: String,
+    pub session_id: String,
+    /// Agent whose work is being scored.
+    pub agent_id: Option<String>,
+    pub reviewer_id: Option<String>,
+    pub reviewer_name: String,
+    pub start_message_id: String,
+    pub end_message_id: String,
+    /// Rubric criterion the score applies to.
+    pub criterion: String,
+    /// 1 (poor) to 5 (excellent).
+    pub score: i32,
+    pub comment: String,
+    /// Messages from `start_message_id` to `end_message_id`, when loaded.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<ChatMessage>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateQaHighlightBody {
+    pub start_message_id: String,
+    /// Defaults to `start_message_id` for a single-message highlight.
+    pub end_message_id: Option<String>,
+    /// Defaults to `overall`.
+    pub criterion: Option<String>,
+    pub score: i32,
+    #[serde(default)]
+    pub comment: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct QaQueueQuery {
+    /// Any date (YYYY-MM-DD) in the ISO week to sample; defaults to the
+    /// current week.
+    pub week: Option<String>,
+    /// Conversations per agent, 1 to 20; defaults to 3.
+    pub per_agent: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct QaReportQuery {
+    /// Number of weeks back from the current one, 1 to 26; defaults to 8.
+    pub weeks: Option<i64>,
+}
+
+/// A callback a visitor booked against an agent's schedule.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CallbackRequest {
+    pub id: String,
+    pub session_id: String,
+    pub contact_id: Option<String>,
+    pub agent_id: Option<String>,
+    pub scheduled_for: String,
+    pub duration_minutes: i32,
+    /// IANA zone the visitor picked the slot in.
+    pub timezone: String,
+    pub phone: String,
+    pub note: String,
+    /// `scheduled`, `completed`, `missed` or `cancelled`.
+    pub status: String,
+    pub outcome: String,
+    pub reminded_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub completed_by: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// A bookable callback or meeting start with at least one free agent.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BookingSlot {
+    pub starts_at: String,
+    pub ends_at: String,
+    /// Start time in the visitor's zone, e.g. "Tue 14 Apr, 09:30".
+    pub label: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCallbackBody {
+    pub starts_at: String,
+    #[serde(default)]
+    pub phone: String,
+    #[serde(default)]
+    pub note: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchCallbackBody {
+    pub status: Option<String>,
+    pub outcome: Option<String>,
+    /// Reassign to another agent; they must be free at the booked time.
+    pub agent_id: Option<String>,
+}
+
+/// An agent's connected Google or Microsoft calendar. Tokens stay server side.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarConnection {
+    pub id: String,
+    pub agent_id: String,
+    /// `google` or `microsoft`.
+    pub provider: String,
+    pub account_email: String,
+    pub calendar_id: String,
+    /// `active` or `error` (tokens rejected; reconnect to fix).
+    pub status: String,
+    pub last_error: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// A meeting a visitor booked in an agent's connected calendar.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Meeting {
+    pub id: String,
+    pub session_id: String,
+    pub agent_id: Option<String>,
+    pub connection_id: Option<String>,
+    pub provider: String,
+    pub external_event_id: String,
+    pub title: String,
+    pub starts_at: String,
+    pub ends_at: String,
+    /// IANA zone the visitor booked in.
+    pub timezone: String,
+    pub visitor_email: String,
+    pub visitor_name: String,
+    /// `scheduled` or `cancelled`.
+    pub status: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMeetingBody {
+    pub starts_at: String,
+    /// Falls back to the session contact's email.
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+/// A workspace's Zendesk or Jira account. The API token is never returned.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TicketIntegration {
+    pub id: String,
+    /// `zendesk` or `jira`.
+    pub provider: String,
+    /// `https://{subdomain}.zendesk.com` or `https://{site}.atlassian.net`.
+    pub base_url: String,
+    pub account_email: String,
+    pub has_api_token: bool,
+    /// Jira project issues are created in.
+    pub project_key: String,
+    pub issue_type: String,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutTicketIntegrationBody {
+    pub base_url: String,
+    pub account_email: String,
+    /// Empty keeps the stored token.
+    #[serde(default)]
+    pub api_token: String,
+    #[serde(default)]
+    pub project_key: String,
+    pub issue_type: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+/// An external ticket a session was escalated to.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionTicket {
+    pub id: String,
+    pub session_id: String,
+    pub provider: String,
+    /// Zendesk ticket id or Jira issue key.
+    pub external_id: String,
+    pub url: String,
+    pub subject: String,
+    /// Status as the provider names it, e.g. `open` or `In Progress`.
+    pub status: String,
+    pub resolved: bool,
+    pub created_by: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSessionTicketBody {
+    pub provider: String,
+    /// Defaults to one derived from the contact.
+    pub subject: Option<String>,
+    /// Added below the transcript summary.
+    #[serde(default)]
+    pub note: String,
+    /// Zendesk priority: `low`, `normal`, `high` or `urgent`.
+    pub priority: Option<String>,
+}
+
+/// Maps a CRM contact field to a contact custom attribute.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CrmFieldMapping {
+    /// HubSpot property or Salesforce field; dotted paths like
+    /// `Owner.Name` follow Salesforce relationships when pulling.
+    pub crm_field: String,
+    pub attribute_key: String,
+    /// `pull` (CRM to attribute) or `push` (attribute to CRM).
+    pub direction: String,
 }
 
-#[derive(Debug, Deserialize)]
+/// A connected CRM. Secrets are never returned.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateTeamBody {
-    pub name: String,
+pub struct CrmIntegration {
+    pub id: String,
+    /// `hubspot` or `salesforce`.
+    pub provider: String,
+    /// Salesforce `https://{domain}.my.salesforce.com`; empty for HubSpot.
+    pub instance_url: String,
+    pub has_api_token: bool,
+    pub client_id: String,
+    pub has_client_secret: bool,
+    pub field_mappings: Vec<CrmFieldMapping>,
+    /// Create new contacts in the CRM.
+    pub push_contacts: bool,
+    /// Log a summary of resolved conversations on the CRM record.
+    pub push_summaries: bool,
+    /// Refresh pulled fields when a conversation is opened.
+    pub pull_on_open: bool,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateChannelBody {
-    pub channel_type: String,
-    pub name: Option<String>,
+pub struct PutCrmIntegrationBody {
     #[serde(default)]
-    pub config: Option<Value>,
+    pub instance_url: String,
+    /// HubSpot private app token. Empty keeps the stored one.
+    #[serde(default)]
+    pub api_token: String,
+    #[serde(default)]
+    pub client_id: String,
+    /// Salesforce connected app secret. Empty keeps the stored one.
+    #[serde(default)]
+    pub client_secret: String,
+    /// Omitted keeps the stored mappings, or the provider defaults.
+    pub field_mappings: Option<Vec<CrmFieldMapping>>,
+    pub push_contacts: Option<bool>,
+    pub push_summaries: Option<bool>,
+    pub pull_on_open: Option<bool>,
+    pub enabled: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateChannelBody {
-    pub channel_type: Option<String>,
-    pub name: Option<String>,
-    #[serde(default)]
-    pub config: Option<Value>,
-    pub enabled: Option<bool>,
+pub struct CrmSyncLogEntry {
+    pub id: String,
+    pub provider: String,
+    /// `push` or `pull`.
+    pub direction: String,
+    /// `contact`, `summary` or `fields`.
+    pub action: String,
+    pub contact_id: Option<String>,
+    pub session_id: Option<String>,
+    /// `ok` or `error`.
+    pub status: String,
+    pub detail: String,
+    pub created_at: String,
 }
 
-#[derive(Debug, Deserialize)]
+/// One input of a connector: a credential or an action parameter.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct AssignBody {
-    pub agent_id: String,
+pub struct ConnectorField {
+    pub key: String,
+    pub label: String,
+    /// `text`, `textarea`, `url` or `secret`.
+    pub kind: String,
+    pub required: bool,
+    #[serde(default)]
+    pub placeholder: String,
 }
 
-#[derive(Debug, Deserialize)]
+/// What a registered connector needs and accepts, for building forms.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SessionAssigneeBody {
-    pub agent_id: Option<String>,
+pub struct ConnectorDefinition {
+    pub key: String,
+    pub label: String,
+    pub description: String,
+    /// Stored encrypted on the connector account.
+    pub credential_fields: Vec<ConnectorField>,
+    /// Set per flow `action` node; values may use `{{variables}}`.
+    pub action_fields: Vec<ConnectorField>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Stored credentials for a connector. Credential values are never returned.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SessionChannelBody {
-    pub channel: String,
+pub struct ConnectorAccount {
+    pub id: String,
+    pub connector: String,
+    pub name: String,
+    /// Credential fields that have a value.
+    pub configured_fields: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SessionTeamBody {
-    pub team_id: Option<String>,
+pub struct CreateConnectorAccountBody {
+    pub connector: String,
+    pub name: String,
+    #[serde(default)]
+    pub credentials: HashMap<String, String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct NoteBody {
-    pub text: String,
+pub struct PatchConnectorAccountBody {
+    pub name: Option<String>,
+    /// Merged into the stored credentials; empty values keep what is stored.
+    #[serde(default)]
+    pub credentials: HashMap<String, String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SessionFlowBody {
-    pub flow_id: Option<String>,
+pub struct TestConnectorActionBody {
+    #[serde(default)]
+    pub params: HashMap<String, String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SessionHandoverBody {
-    pub active: bool,
+pub struct PutSessionSkillsBody {
+    pub skills: Vec<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SendWhatsappTemplateBody {
-    pub template_name: String,
-    pub language_code: Option<String>,
-    #[serde(default)]
-    pub parameters: Option<Vec<String>>,
+pub struct PatchQueueSettingsBody {
+    pub max_per_agent: Option<i32>,
+    pub team_limits: Option<HashMap<String, i32>>,
+    pub overflow_behavior: Option<String>,
+    /// Empty string clears the backup team.
+    pub backup_team_id: Option<String>,
+    pub overflow_message: Option<String>,
+    pub auto_assign: Option<bool>,
+    pub reply_collision_window_secs: Option<i32>,
+    pub waiting_alert_mins: Option<Vec<i32>>,
+    /// Replaces the whole map; an empty team id drops that language.
+    pub language_teams: Option<HashMap<String, String>>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Bot persona plus the channels it answers on.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateTenantBody {
-    pub name: String,
+pub struct BotSettings {
+    pub bot_name: String,
+    pub bot_avatar_url: String,
+    pub bot_personality: String,
+    pub bot_enabled_by_default: bool,
+    /// Keep the bot away from sessions until the visitor accepts AI processing.
+    pub ai_consent_required: bool,
+    /// Consent prompt shown in the widget; empty uses the widget default.
+    pub ai_consent_text: String,
+    /// Visitor messages sent within this many milliseconds of each other are
+    /// answered as one turn; 0 answers each message on its own.
+    pub burst_window_ms: i32,
+    /// AI answers scored below this (0-1) are held back; 0 sends every answer.
+    pub ai_confidence_threshold: f64,
+    /// `clarify` asks the visitor a clarifying question, `handover` escalates.
+    pub low_confidence_action: String,
+    pub channels: Vec<BotChannelToggle>,
+}
+
+/// Per-channel bot switch. `None` follows `botEnabledByDefault`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BotChannelToggle {
+    pub channel_id: String,
     #[serde(default)]
-    pub workspace_username: Option<String>,
+    pub channel_type: String,
     #[serde(default)]
-    pub login_ticket: Option<String>,
+    pub name: String,
+    pub bot_enabled: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct PatchTenantSettingsBody {
-    pub brand_name: Option<String>,
-    pub workspace_short_bio: Option<String>,
-    pub workspace_description: Option<String>,
-    pub primary_color: Option<String>,
-    pub accent_color: Option<String>,
-    pub logo_url: Option<String>,
-    pub privacy_url: Option<String>,
-    pub launcher_position: Option<String>,
-    pub welcome_text: Option<String>,
+pub struct PutBotSettingsBody {
     pub bot_name: Option<String>,
     pub bot_avatar_url: Option<String>,
+    pub bot_personality: Option<String>,
     pub bot_enabled_by_default: Option<bool>,
+    pub ai_consent_required: Option<bool>,
+    pub ai_consent_text: Option<String>,
+    pub burst_window_ms: Option<i32>,
+    pub ai_confidence_threshold: Option<f64>,
+    pub low_confidence_action: Option<String>,
+    pub channels: Option<Vec<BotChannelToggle>>,
+}
+
+/// Unsaved persona values to preview; missing fields use the stored ones.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BotPromptPreviewBody {
+    pub bot_name: Option<String>,
     pub bot_personality: Option<String>,
+    pub flow_prompt: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchRetentionPolicyBody {
+    pub message_retention_days: Option<i32>,
+    pub note_retention_days: Option<i32>,
+    pub media_retention_days: Option<i32>,
+    pub mode: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateContactBody {
     pub display_name: Option<String>,
@@ -648,7 +2582,7 @@ pub struct CreateContactBody {
     pub location: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PatchContactBody {
     pub display_name: Option<String>,
@@ -661,7 +2595,7 @@ pub struct PatchContactBody {
     pub avatar_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateTagBody {
     pub name: String,
@@ -671,7 +2605,7 @@ pub struct CreateTagBody {
     pub description: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateTagBody {
     pub name: Option<String>,
@@ -679,7 +2613,7 @@ pub struct UpdateTagBody {
     pub description: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateKbCollectionBody {
     pub name: String,
@@ -687,15 +2621,70 @@ pub struct CreateKbCollectionBody {
     pub description: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateKbCollectionBody {
     pub name: Option<String>,
     pub description: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct ListSessionsQuery {
+    /// Only archived sessions.
+    #[serde(default)]
+    pub archived: bool,
+    /// Only soft-deleted sessions (admins and owners).
+    #[serde(default)]
+    pub deleted: bool,
+    /// `priority` (default: urgent first, then most recent) or `recent`.
+    /// Sessions the agent pinned always come first.
+    pub sort: Option<String>,
+    /// Only sessions the agent pinned.
+    #[serde(default)]
+    pub pinned: bool,
+    /// Only sessions the agent starred.
+    #[serde(default)]
+    pub starred: bool,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct ReportRangeQuery {
+    /// RFC3339 start; defaults to seven days before `to`.
+    pub from: Option<String>,
+    /// RFC3339 end; defaults to now.
+    pub to: Option<String>,
+}
+
+/// Signature parameters carried by a signed `/api/media` URL.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MediaAccessQuery {
+    /// Workspace whose key signed the URL.
+    pub t: Option<String>,
+    /// Unix expiry timestamp.
+    pub exp: Option<i64>,
+    /// Hex HMAC-SHA256 signature.
+    pub sig: Option<String>,
+    /// Nonce of a one-time download link.
+    pub n: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct UploadAttachmentQuery {
+    /// Hand out one-time download links for this file.
+    #[serde(default)]
+    pub sensitive: bool,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
 pub struct ListKbArticlesQuery {
     #[serde(default)]
     pub collection_id: String,
@@ -703,7 +2692,7 @@ pub struct ListKbArticlesQuery {
     pub status: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateKbArticleBody {
     pub collection_id: String,
@@ -712,17 +2701,21 @@ pub struct CreateKbArticleBody {
     pub markdown: String,
     #[serde(default)]
     pub status: String,
+    /// Defaults to `en`.
+    #[serde(default)]
+    pub language: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateKbArticleBody {
     pub collection_id: Option<String>,
     pub title: Option<String>,
     pub markdown: Option<String>,
+    pub language: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateKbTagBody {
     pub name: String,
@@ -732,7 +2725,7 @@ pub struct CreateKbTagBody {
     pub description: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KbSearchRequest {
     pub query: String,
@@ -752,7 +2745,7 @@ fn default_tag_color() -> String {
     "#6366f1".to_string()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomAttributeDefinition {
     pub id: String,
@@ -765,7 +2758,7 @@ pub struct CustomAttributeDefinition {
     pub updated_at: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateAttributeDefBody {
     pub display_name: String,
@@ -780,49 +2773,89 @@ fn default_attr_model() -> String {
     "contact".to_string()
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateAttributeDefBody {
     pub display_name: Option<String>,
     pub description: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionTagBody {
     pub tag_id: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionContactBody {
     pub contact_id: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SetAttributeBody {
     pub attribute_key: String,
     pub attribute_value: String,
 }
 
-#[derive(Debug, Deserialize)]
+/// Widget preferences of a visitor, keyed by the visitor id bound to their
+/// session so they follow the visitor across devices.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetPreferences {
+    pub sound_enabled: bool,
+    pub email_transcript: bool,
+    /// BCP 47 tag the widget UI should use; empty follows the browser.
+    pub language: String,
+}
+
+impl Default for WidgetPreferences {
+    fn default() -> Self {
+        Self {
+            sound_enabled: true,
+            email_transcript: false,
+            language: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PutWidgetPreferencesBody {
+    pub sound_enabled: Option<bool>,
+    pub email_transcript: Option<bool>,
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateCsatBody {
     pub score: i32,
     pub comment: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionMetaBody {
     pub status: Option<String>,
     pub priority: Option<String>,
     pub snooze_mode: Option<String>,
     pub snoozed_until: Option<String>,
+    /// Replaces the generated title for good; empty clears it.
+    pub title: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Pin or star a session for the calling agent; omitted fields keep their
+/// value.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPinBody {
+    pub pinned: Option<bool>,
+    pub starred: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StartWhatsappCallBody {
     #[serde(default)]
@@ -831,14 +2864,43 @@ pub struct StartWhatsappCallBody {
     pub note: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StartCallBody {
+    /// `jitsi`, `daily` or `twilio`; defaults to the `CALL_PROVIDER` env var.
+    #[serde(default)]
+    pub provider: String,
+    /// `video` (default) or `voice`.
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default)]
+    pub note: String,
+}
+
+/// A meeting link escalated from a conversation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCall {
+    pub id: String,
+    pub session_id: String,
+    pub provider: String,
+    pub kind: String,
+    pub room: String,
+    pub join_url: String,
+    pub started_by_agent_id: Option<String>,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    pub duration_sec: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WhatsappCallSessionBody {
     pub sdp_type: String,
     pub sdp: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WhatsappCallActionBody {
     #[serde(default)]
@@ -852,7 +2914,7 @@ pub struct WhatsappCallActionBody {
     pub session: Option<WhatsappCallSessionBody>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateCannedReplyBody {
     pub title: String,
@@ -863,7 +2925,7 @@ pub struct CreateCannedReplyBody {
     pub category: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateCannedReplyBody {
     pub title: Option<String>,
@@ -872,7 +2934,53 @@ pub struct UpdateCannedReplyBody {
     pub category: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReplyTemplateBody {
+    pub title: String,
+    pub body: String,
+    #[serde(default)]
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateReplyTemplateBody {
+    pub title: Option<String>,
+    pub body: Option<String>,
+    /// An empty string shares the template with the whole workspace.
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInboxViewBody {
+    pub name: String,
+    #[serde(default)]
+    pub filters: InboxViewFilters,
+    pub sort: Option<String>,
+    #[serde(default)]
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateInboxViewBody {
+    pub name: Option<String>,
+    pub filters: Option<InboxViewFilters>,
+    pub sort: Option<String>,
+    /// An empty string makes the view private again.
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDefaultInboxViewBody {
+    /// `None` goes back to the built-in inbox.
+    pub view_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateFlowBody {
     pub name: String,
@@ -890,9 +2998,11 @@ pub struct CreateFlowBody {
     pub ai_tool: bool,
     #[serde(default)]
     pub ai_tool_description: String,
+    #[serde(default)]
+    pub max_steps: i32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateFlowBody {
     pub name: Option<String>,
@@ -903,6 +3013,89 @@ pub struct UpdateFlowBody {
     pub input_variables: Option<Vec<FlowInputVariable>>,
     pub ai_tool: Option<bool>,
     pub ai_tool_description: Option<String>,
+    pub max_steps: Option<i32>,
+}
+
+/// Self-contained, workspace-independent export of a flow. Ids inside the
+/// bundle are only meaningful within it and are remapped on import.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowBundle {
+    pub format: String,
+    pub version: u32,
+    #[serde(default)]
+    pub exported_at: String,
+    pub flow: FlowBundleFlow,
+    /// Sub-flows reached through `start_flow` nodes.
+    #[serde(default)]
+    pub flows: Vec<FlowBundleFlow>,
+    /// Canned replies whose `/shortcut` appears in node text.
+    #[serde(default)]
+    pub canned_replies: Vec<FlowBundleCannedReply>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowBundleFlow {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub nodes: Vec<FlowNode>,
+    #[serde(default)]
+    pub edges: Vec<FlowEdge>,
+    #[serde(default)]
+    pub input_variables: Vec<FlowInputVariable>,
+    #[serde(default)]
+    pub ai_tool: bool,
+    #[serde(default)]
+    pub ai_tool_description: String,
+    #[serde(default)]
+    pub max_steps: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowBundleCannedReply {
+    pub title: String,
+    pub shortcut: String,
+    #[serde(default)]
+    pub category: String,
+    pub body: String,
+}
+
+/// Starter flow shipped with the server. Strings inside `flow` may contain
+/// `[[placeholder]]` tokens that are filled in when the template is instantiated.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowTemplate {
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub placeholders: Vec<FlowTemplatePlaceholder>,
+    pub flow: FlowBundleFlow,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowTemplatePlaceholder {
+    pub key: String,
+    pub label: String,
+    #[serde(default)]
+    pub default: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InstantiateFlowTemplateBody {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub values: HashMap<String, String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -5,6 +5,10 @@ import remarkGfm from "remark-gfm";
 const API_URL = import.meta.env.VITE_API_URL ?? "http://localhost:4000";
 const WS_URL = import.meta.env.VITE_WS_URL ?? "ws://localhost:4000/ws";
 const API_BASE = API_URL.replace(/\/+$/, "");
+// Fallback for networks that block WebSockets: events arrive over SSE and
+// are posted back over HTTP, in the same envelope.
+const SSE_URL = `${API_BASE}/api/realtime/sse`;
+const WS_FAILURES_BEFORE_SSE = 2;
 
 function resolveApiUrl(url) {
   const value = String(url || "").trim();
@@ -48,6 +52,8 @@ const icon = (
   </svg>
 );
 
+const returningVisitor = Boolean(localStorage.getItem("chat_visitor_id"));
+
 function getOrCreateVisitorId() {
   let vid = localStorage.getItem("chat_visitor_id");
   if (!vid) {
@@ -57,6 +63,47 @@ function getOrCreateVisitorId() {
   return vid;
 }
 
+function getDeviceType() {
+  const ua = navigator.userAgent.toLowerCase();
+  if (/ipad|tablet/.test(ua) || (/android/.test(ua) && !/mobile/.test(ua))) {
+    return "tablet";
+  }
+  if (/mobi|iphone/.test(ua)) return "mobile";
+  return "desktop";
+}
+
+// Targeting context for flow triggers. Custom attributes come from the embed
+// script via `window.CHAT_VISITOR_ATTRIBUTES`.
+function getVisitorContext() {
+  const attributes =
+    typeof window.CHAT_VISITOR_ATTRIBUTES === "object" &&
+    window.CHAT_VISITOR_ATTRIBUTES !== null
+      ? window.CHAT_VISITOR_ATTRIBUTES
+      : {};
+  return {
+    pageUrl: window.location.href,
+    referrer: document.referrer,
+    deviceType: getDeviceType(),
+    returning: returningVisitor,
+    attributes,
+  };
+}
+
+function getClientInfo() {
+  let timezone = "";
+  try {
+    timezone = Intl.DateTimeFormat().resolvedOptions().timeZone || "";
+  } catch {
+    timezone = "";
+  }
+  return {
+    screenWidth: window.screen?.width || 0,
+    screenHeight: window.screen?.height || 0,
+    language: navigator.language || "",
+    timezone,
+  };
+}
+
 export default function App() {
   const [open, setOpen] = useState(false);
   const visitorId = useRef(getOrCreateVisitorId());
@@ -67,11 +114,14 @@ export default function App() {
   const [sessionId, setSessionId] = useState(
     localStorage.getItem("chat_session_id") || "",
   );
+  const sessionTokenRef = useRef(
+    localStorage.getItem("chat_session_token") || "",
+  );
   const [messages, setMessages] = useState([]);
   const [text, setText] = useState("");
   const [ready, setReady] = useState(false);
   const [agentTyping, setAgentTyping] = useState(false);
-  const [typingAgent, setTypingAgent] = useState(null);
+  const [typingAgents, setTypingAgents] = useState([]);
   const [dismissedSuggestionsFor, setDismissedSuggestionsFor] = useState("");
   const [carouselSelections, setCarouselSelections] = useState({});
   const [selectSelections, setSelectSelections] = useState({});
@@ -81,9 +131,12 @@ export default function App() {
   const [csatHover, setCsatHover] = useState({});
   const [bootstrapAgents, setBootstrapAgents] = useState([]);
   const [brandSettings, setBrandSettings] = useState(null);
+  const [aiConsent, setAiConsent] = useState(null);
   const [setupError, setSetupError] = useState("");
+  const [networkDenial, setNetworkDenial] = useState("");
 
   const wsRef = useRef(null);
+  const wsFailuresRef = useRef(0);
   const reconnectTimerRef = useRef(null);
   const visitorTypingIdleTimerRef = useRef(null);
   const tempIdRef = useRef(0);
@@ -91,6 +144,30 @@ export default function App() {
   const stickToBottomRef = useRef(true);
   const openRef = useRef(open);
 
+  const storeSessionToken = (token) => {
+    sessionTokenRef.current = token || "";
+    if (token) localStorage.setItem("chat_session_token", token);
+    else localStorage.removeItem("chat_session_token");
+  };
+
+  const adoptSession = (nextSessionId, token) => {
+    storeSessionToken(token);
+    setSessionId(nextSessionId);
+    localStorage.setItem("chat_session_id", nextSessionId);
+  };
+
+  // Session tokens expire; drop the session so bootstrap starts a fresh one.
+  const resetSession = () => {
+    storeSessionToken("");
+    localStorage.removeItem("chat_session_id");
+    setSessionId("");
+  };
+
+  const sessionHeaders = (extra = {}) => ({
+    ...extra,
+    "X-Session-Token": sessionTokenRef.current,
+  });
+
   const sendWsEvent = (event, data) => {
     const ws = wsRef.current;
     if (!ws || ws.readyState !== WebSocket.OPEN) return;
@@ -140,7 +217,13 @@ export default function App() {
   };
 
   const loadHistory = async (id) => {
-    const res = await fetch(`${API_URL}/api/session/${id}/messages`);
+    const res = await fetch(`${API_URL}/api/session/${id}/messages`, {
+      headers: sessionHeaders(),
+    });
+    if (res.status === 401) {
+      resetSession();
+      return;
+    }
     const data = await res.json();
     setMessages(Array.isArray(data.messages) ? data.messages : []);
     setReady(true);
@@ -151,10 +234,13 @@ export default function App() {
     const boot = async () => {
       // Fetch widget bootstrap (settings + online agents)
       try {
-        const bootstrapUrl = channelId
+        let bootstrapUrl = channelId
           ? `${API_URL}/api/widget/bootstrap?tenant_id=${encodeURIComponent(tenantId)}&channel_id=${encodeURIComponent(channelId)}`
           : `${API_URL}/api/widget/bootstrap?tenant_id=${encodeURIComponent(tenantId)}`;
-        const bRes = await fetch(bootstrapUrl);
+        if (sessionId) {
+          bootstrapUrl += `&session_id=${encodeURIComponent(sessionId)}`;
+        }
+        const bRes = await fetch(bootstrapUrl, { headers: sessionHeaders() });
         const bData = await bRes.json();
         if (!bRes.ok) {
           setSetupError(bData?.error || `Bootstrap failed (${bRes.status})`);
@@ -175,6 +261,7 @@ export default function App() {
           }
         }
         if (Array.isArray(bData?.agents)) setBootstrapAgents(bData.agents);
+        setAiConsent(bData?.aiConsent || null);
       } catch (err) {
         setSetupError("Could not reach the server");
         localStorage.removeItem("chat_tenant_id");
@@ -193,6 +280,10 @@ export default function App() {
         }),
       });
       const data = await res.json();
+      if (data?.code === "network_denied") {
+        setNetworkDenial(data.error);
+        return;
+      }
       if (!res.ok) {
         setSetupError(data?.error || `Session creation failed (${res.status})`);
         localStorage.removeItem("chat_tenant_id");
@@ -200,8 +291,7 @@ export default function App() {
         setTenantId("");
         return;
       }
-      setSessionId(data.sessionId);
-      localStorage.setItem("chat_session_id", data.sessionId);
+      adoptSession(data.sessionId, data.sessionToken);
     };
 
     boot().catch((error) => console.error("session bootstrap failed", error));
@@ -213,81 +303,194 @@ export default function App() {
     let closedByCleanup = false;
     setAgentTyping(false);
 
-    const connect = () => {
+    const joinSession = () => {
+      sendWsEvent("widget:join", {
+        sessionId,
+        sessionToken: sessionTokenRef.current,
+        visitorId: visitorId.current,
+        tenantId: tenantId,
+        context: getVisitorContext(),
+        clientInfo: getClientInfo(),
+      });
+      if (openRef.current) {
+        sendWsEvent("widget:opened", { sessionId });
+      }
+    };
+
+    const parseEnvelope = (raw) => {
+      try {
+        return JSON.parse(raw);
+      } catch {
+        return null;
+      }
+    };
+
+    const handleEnvelope = (envelope, close) => {
+      if (envelope?.event === "session:history") {
+        const history = Array.isArray(envelope.data) ? envelope.data : [];
+        setMessages((prev) => {
+          const current = Array.isArray(prev) ? prev : [];
+          if (current.length > 0 && history.length === 0) return current;
+          const byId = new Map();
+          [...current, ...history].forEach((m) => {
+            if (m?.id) byId.set(m.id, m);
+          });
+          return [...byId.values()].sort((a, b) => {
+            return String(a.createdAt || "").localeCompare(
+              String(b.createdAt || ""),
+            );
+          });
+        });
+        setReady(true);
+      }
+
+      if (envelope?.event === "message:new") {
+        mergeMessage(envelope.data);
+      }
+
+      if (envelope?.event === "session:token") {
+        if (envelope?.data?.sessionId !== sessionId) return;
+        storeSessionToken(envelope.data.sessionToken);
+      }
+
+      if (
+        envelope?.event === "error" &&
+        envelope?.data?.code === "network_denied"
+      ) {
+        closedByCleanup = true;
+        setNetworkDenial(envelope.data.message);
+        close();
+        return;
+      }
+
+      if (
+        envelope?.event === "error" &&
+        envelope?.data?.message === "invalid session token" &&
+        envelope?.data?.sessionId === sessionId
+      ) {
+        closedByCleanup = true;
+        resetSession();
+        return;
+      }
+
+      if (envelope?.event === "session:switched") {
+        const nextSessionId = envelope?.data?.sessionId;
+        if (!nextSessionId || nextSessionId === sessionId) return;
+        adoptSession(nextSessionId, envelope.data.sessionToken);
+        setMessages([]);
+        setReady(false);
+        setAgentTyping(false);
+      }
+
+      if (envelope?.event === "campaign:message") {
+        if (envelope?.data?.sessionId === sessionId) setOpen(true);
+      }
+
+      if (envelope?.event === "typing") {
+        const payload = envelope.data ?? {};
+        if (payload.sessionId !== sessionId) return;
+        if (payload.sender !== "agent") return;
+        setAgentTyping(Boolean(payload.active));
+        // `agents` lists every human typing; the bot types with none.
+        const typers = Array.isArray(payload.agents)
+          ? payload.agents
+          : payload.agentName
+            ? [{ name: payload.agentName, avatarUrl: payload.agentAvatarUrl }]
+            : [];
+        setTypingAgents(
+          typers.map((typer) => ({
+            agentName: typer.name || "",
+            agentAvatarUrl: typer.avatarUrl || "",
+          })),
+        );
+      }
+    };
+
+    const connectWebSocket = () => {
       const ws = new WebSocket(WS_URL);
+      let opened = false;
       wsRef.current = ws;
 
       ws.addEventListener("open", () => {
-        sendWsEvent("widget:join", {
-          sessionId,
-          visitorId: visitorId.current,
-          tenantId: tenantId,
-        });
-        if (openRef.current) {
-          sendWsEvent("widget:opened", { sessionId });
-        }
+        opened = true;
+        wsFailuresRef.current = 0;
+        joinSession();
       });
 
       ws.addEventListener("message", (event) => {
-        let envelope;
-        try {
-          envelope = JSON.parse(event.data);
-        } catch {
-          return;
-        }
-
-        if (envelope?.event === "session:history") {
-          const history = Array.isArray(envelope.data) ? envelope.data : [];
-          setMessages((prev) => {
-            const current = Array.isArray(prev) ? prev : [];
-            if (current.length > 0 && history.length === 0) return current;
-            const byId = new Map();
-            [...current, ...history].forEach((m) => {
-              if (m?.id) byId.set(m.id, m);
-            });
-            return [...byId.values()].sort((a, b) => {
-              return String(a.createdAt || "").localeCompare(
-                String(b.createdAt || ""),
-              );
-            });
-          });
-          setReady(true);
-        }
+        const envelope = parseEnvelope(event.data);
+        if (envelope) handleEnvelope(envelope, () => ws.close());
+      });
 
-        if (envelope?.event === "message:new") {
-          mergeMessage(envelope.data);
-        }
+      ws.addEventListener("close", () => {
+        if (closedByCleanup) return;
+        if (!opened) wsFailuresRef.current += 1;
+        reconnectTimerRef.current = setTimeout(connect, 800);
+      });
+    };
 
-        if (envelope?.event === "session:switched") {
-          const nextSessionId = envelope?.data?.sessionId;
-          if (!nextSessionId || nextSessionId === sessionId) return;
-          setSessionId(nextSessionId);
-          localStorage.setItem("chat_session_id", nextSessionId);
-          setMessages([]);
-          setReady(false);
-          setAgentTyping(false);
-        }
+    // Shaped like a WebSocket so sendWsEvent works unchanged. Posts are
+    // chained to keep events in order.
+    const connectEventSource = () => {
+      const source = new EventSource(SSE_URL);
+      let pending = Promise.resolve();
+      const transport = {
+        readyState: WebSocket.CONNECTING,
+        url: "",
+        key: "",
+        send(payload) {
+          const { url, key } = transport;
+          pending = pending
+            .then(() =>
+              fetch(url, {
+                method: "POST",
+                headers: {
+                  "Content-Type": "application/json",
+                  "X-Realtime-Key": key,
+                },
+                body: payload,
+              }),
+            )
+            .catch((error) => console.error("realtime send failed", error));
+        },
+        close() {
+          transport.readyState = WebSocket.CLOSED;
+          source.close();
+        },
+      };
+      wsRef.current = transport;
 
-        if (envelope?.event === "typing") {
-          const payload = envelope.data ?? {};
-          if (payload.sessionId !== sessionId) return;
-          if (payload.sender !== "agent") return;
-          setAgentTyping(Boolean(payload.active));
-          if (payload.active) {
-            setTypingAgent({
-              agentName: payload.agentName || "",
-              agentAvatarUrl: payload.agentAvatarUrl || "",
-            });
-          }
+      source.addEventListener("message", (event) => {
+        const envelope = parseEnvelope(event.data);
+        if (!envelope) return;
+        if (envelope.event === "realtime:ready") {
+          transport.url = `${SSE_URL}/${envelope.data?.clientId}`;
+          transport.key = envelope.data?.clientKey || "";
+          transport.readyState = WebSocket.OPEN;
+          joinSession();
+          return;
         }
+        handleEnvelope(envelope, () => transport.close());
       });
 
-      ws.addEventListener("close", () => {
-        if (closedByCleanup) return;
+      // EventSource reconnects on its own and the new `realtime:ready`
+      // rejoins; only a source that gave up needs a fresh start.
+      source.addEventListener("error", () => {
+        if (transport.readyState === WebSocket.CLOSED) return;
+        transport.readyState = WebSocket.CONNECTING;
+        if (closedByCleanup || source.readyState !== EventSource.CLOSED) return;
         reconnectTimerRef.current = setTimeout(connect, 800);
       });
     };
 
+    const connect = () => {
+      if (wsFailuresRef.current >= WS_FAILURES_BEFORE_SSE) {
+        connectEventSource();
+      } else {
+        connectWebSocket();
+      }
+    };
+
     setReady(false);
     loadHistory(sessionId).catch((error) =>
       console.error("failed to load history", error),
@@ -310,6 +513,65 @@ export default function App() {
     sendWsEvent("widget:opened", { sessionId });
   }, [open, sessionId]);
 
+  // Session tokens are `<exp>.<sig>`; trade the current one for a fresh one
+  // before it expires so long conversations can still reconnect.
+  useEffect(() => {
+    if (!sessionId) return;
+    let timer = null;
+    const schedule = () => {
+      const exp = Number(String(sessionTokenRef.current).split(".")[0]);
+      if (!exp) return;
+      const remaining = exp * 1000 - Date.now();
+      const delay = Math.max(
+        remaining - Math.min(5 * 60 * 1000, remaining / 2),
+        10000,
+      );
+      timer = setTimeout(refresh, delay);
+    };
+    const refresh = async () => {
+      try {
+        const res = await fetch(`${API_URL}/api/session/${sessionId}/token`, {
+          method: "POST",
+          headers: sessionHeaders(),
+        });
+        if (res.status === 401) {
+          resetSession();
+          return;
+        }
+        const data = await res.json();
+        if (res.ok && data?.sessionToken) storeSessionToken(data.sessionToken);
+      } catch {
+        // Retried on the next schedule.
+      }
+      schedule();
+    };
+    schedule();
+    return () => clearTimeout(timer);
+  }, [sessionId]);
+
+  // Report client-side navigation so proactive campaigns see the current page.
+  useEffect(() => {
+    if (!sessionId) return;
+    let lastUrl = window.location.href;
+    const reportPageView = () => {
+      if (window.location.href === lastUrl) return;
+      lastUrl = window.location.href;
+      sendWsEvent("widget:page-view", {
+        sessionId,
+        context: getVisitorContext(),
+        clientInfo: getClientInfo(),
+      });
+    };
+    window.addEventListener("popstate", reportPageView);
+    window.addEventListener("hashchange", reportPageView);
+    const timer = setInterval(reportPageView, 1000);
+    return () => {
+      window.removeEventListener("popstate", reportPageView);
+      window.removeEventListener("hashchange", reportPageView);
+      clearInterval(timer);
+    };
+  }, [sessionId]);
+
   useEffect(() => {
     if (!listRef.current) return;
     if (!stickToBottomRef.current) return;
@@ -353,19 +615,23 @@ export default function App() {
 
     fetch(`${API_URL}/api/session/${sessionId}/message`, {
       method: "POST",
-      headers: { "Content-Type": "application/json" },
+      headers: sessionHeaders({ "Content-Type": "application/json" }),
       body: JSON.stringify({ sender: "visitor", text: value }),
     })
-      .then((res) => res.json())
+      .then((res) => {
+        if (res.status === 401) resetSession();
+        return res.json();
+      })
       .then((data) => {
         if (!data?.message) return;
         const nextSessionId = data?.sessionId;
         if (nextSessionId && nextSessionId !== sessionId) {
-          setSessionId(nextSessionId);
-          localStorage.setItem("chat_session_id", nextSessionId);
+          adoptSession(nextSessionId, data.sessionToken);
           setMessages([]);
           setReady(false);
           setAgentTyping(false);
+        } else if (data?.sessionToken) {
+          storeSessionToken(data.sessionToken);
         }
         setMessages((prev) => {
           const list = Array.isArray(prev) ? prev : [];
@@ -421,7 +687,9 @@ export default function App() {
         wType === "quick_input" ||
         wType === "input_form" ||
         wType === "select" ||
-        wType === "csat"
+        wType === "csat" ||
+        wType === "callback" ||
+        wType === "book_meeting"
       ) {
         if (submittedWidgets[m.id]) return false;
         return Boolean(m.widget?.disableComposer);
@@ -431,6 +699,94 @@ export default function App() {
     return false;
   }, [messages, submittedWidgets, chatClosed]);
 
+  const answerAiConsent = async (granted) => {
+    if (!sessionId) return;
+    const res = await fetch(`${API_URL}/api/session/${sessionId}/ai-consent`, {
+      method: "PUT",
+      headers: sessionHeaders({ "Content-Type": "application/json" }),
+      body: JSON.stringify({ granted }),
+    });
+    if (res.status === 401) {
+      resetSession();
+      return;
+    }
+    const data = await res.json();
+    if (!res.ok) return;
+    setAiConsent((prev) => ({
+      ...(prev || {}),
+      status: data?.consent?.status || (granted ? "granted" : "declined"),
+    }));
+  };
+
+  const bookCallback = async (message) => {
+    if (!sessionId) return;
+    const values = formInputs[formKey(message.id)] || {};
+    const slot = (message.widget?.slots || []).find(
+      (item) => item.startsAt === values.startsAt,
+    );
+    if (!slot) return;
+    const res = await fetch(`${API_URL}/api/session/${sessionId}/callbacks`, {
+      method: "POST",
+      headers: sessionHeaders({ "Content-Type": "application/json" }),
+      body: JSON.stringify({
+        startsAt: slot.startsAt,
+        phone: values.phone || "",
+      }),
+    });
+    if (res.status === 401) {
+      resetSession();
+      return;
+    }
+    const data = await res.json().catch(() => ({}));
+    if (!res.ok) {
+      setFormInputs((prev) => ({
+        ...prev,
+        [formKey(message.id)]: {
+          ...values,
+          startsAt: "",
+          error: data?.error || "Could not book that time",
+        },
+      }));
+      return;
+    }
+    markWidgetSubmitted(message.id, slot.label);
+  };
+
+  const bookMeeting = async (message) => {
+    if (!sessionId) return;
+    const values = formInputs[formKey(message.id)] || {};
+    const slot = (message.widget?.slots || []).find(
+      (item) => item.startsAt === values.startsAt,
+    );
+    if (!slot) return;
+    const res = await fetch(`${API_URL}/api/session/${sessionId}/meetings`, {
+      method: "POST",
+      headers: sessionHeaders({ "Content-Type": "application/json" }),
+      body: JSON.stringify({
+        startsAt: slot.startsAt,
+        email: values.email || "",
+        name: values.name || "",
+      }),
+    });
+    if (res.status === 401) {
+      resetSession();
+      return;
+    }
+    const data = await res.json().catch(() => ({}));
+    if (!res.ok) {
+      setFormInputs((prev) => ({
+        ...prev,
+        [formKey(message.id)]: {
+          ...values,
+          startsAt: res.status === 409 ? "" : values.startsAt,
+          error: data?.error || "Could not book that time",
+        },
+      }));
+      return;
+    }
+    markWidgetSubmitted(message.id, slot.label);
+  };
+
   const markWidgetSubmitted = (messageId, displayValue) => {
     setSubmittedWidgets((prev) => ({
       ...prev,
@@ -445,6 +801,14 @@ export default function App() {
     return name[0].toUpperCase();
   };
 
+  const typingLabel = (typers) => {
+    const names = typers.map((typer) => typer.agentName).filter(Boolean);
+    if (names.length === 0) return "Agent is typing";
+    if (names.length === 1) return `${names[0]} is typing`;
+    if (names.length === 2) return `${names[0]} and ${names[1]} are typing`;
+    return `${names[0]} and ${names.length - 1} others are typing`;
+  };
+
   const renderAgentAvatar = (msg, size = 28) => {
     const avatarUrl =
       msg?.agentAvatarUrl ||
@@ -482,8 +846,7 @@ export default function App() {
     });
     const data = await res.json();
     if (!data?.sessionId) return;
-    setSessionId(data.sessionId);
-    localStorage.setItem("chat_session_id", data.sessionId);
+    adoptSession(data.sessionId, data.sessionToken);
     setMessages([]);
     setReady(false);
     setText("");
@@ -495,6 +858,7 @@ export default function App() {
     if (!sessionId) return;
     await fetch(`${API_URL}/api/session/${sessionId}/close`, {
       method: "POST",
+      headers: sessionHeaders(),
     });
   };
 
@@ -509,7 +873,12 @@ export default function App() {
       </button>
 
       <section className={`panel ${open ? "panel-open" : "panel-closed"}`}>
-        {!tenantId ? (
+        {networkDenial ? (
+          <div className="setup-screen">
+            <div className="setup-icon">{icon}</div>
+            <p className="setup-desc">{networkDenial}</p>
+          </div>
+        ) : !tenantId ? (
           <div className="setup-screen">
             <div className="setup-icon">{icon}</div>
             <h2 className="setup-title">Widget Setup</h2>
@@ -625,6 +994,12 @@ export default function App() {
                     (!prev ||
                       prev.sender !== "agent" ||
                       prev.agentId !== m.agentId);
+                  const showParticipantName =
+                    m.sender === "visitor" &&
+                    Boolean(m.participantName) &&
+                    (!prev ||
+                      prev.sender !== "visitor" ||
+                      prev.participantId !== m.participantId);
                   return (
                     <div
                       key={m.id}
@@ -651,6 +1026,11 @@ export default function App() {
                                 {m.agentName || brandSettings?.botName}
                               </span>
                             )}
+                            {showParticipantName && (
+                              <span className="agent-name-label participant-name-label">
+                                {m.participantName}
+                              </span>
+                            )}
                             <div className={`bubble bubble-${m.sender}`}>
                               <div className="md-content">
                                 <ReactMarkdown remarkPlugins={[remarkGfm]}>
@@ -725,6 +1105,46 @@ export default function App() {
                                   </div>
                                 </a>
                               )}
+                            {m.sender === "agent" &&
+                              m.widget?.type === "kb_citations" &&
+                              Array.isArray(m.widget?.articles) &&
+                              m.widget.articles.length > 0 && (
+                                <div className="message-widget kb-citations">
+                                  <p className="link-preview-site">Sources</p>
+                                  <ul>
+                                    {m.widget.articles.map((article) => (
+                                      <li key={article?.id || article?.slug}>
+                                        {article?.title || "Article"}
+                                      </li>
+                                    ))}
+                                  </ul>
+                                </div>
+                              )}
+                            {m.sender === "agent" &&
+                              m.widget?.type === "call" && (
+                                <a
+                                  className="message-widget link-preview-card"
+                                  href={m.widget?.joinUrl || "#"}
+                                  target="_blank"
+                                  rel="noreferrer noopener"
+                                >
+                                  <div className="link-preview-body">
+                                    <p className="link-preview-site">
+                                      {m.widget?.kind === "voice"
+                                        ? "Voice call"
+                                        : "Video call"}
+                                    </p>
+                                    <h4>
+                                      {m.widget?.status === "ended"
+                                        ? "Call ended"
+                                        : "Join the call"}
+                                    </h4>
+                                    <span className="link-preview-url">
+                                      {m.widget?.joinUrl || ""}
+                                    </span>
+                                  </div>
+                                </a>
+                              )}
                             {m.sender === "agent" &&
                               m.widget?.type === "attachment" && (
                                 <div className="message-widget attachment-widget">
@@ -738,13 +1158,21 @@ export default function App() {
                                     m.widget?.attachmentType === "sticker") &&
                                   attachmentUrl ? (
                                     <a
-                                      href={attachmentUrl}
+                                      href={
+                                        resolveApiUrl(
+                                          m.widget?.previewUrl || "",
+                                        ) || attachmentUrl
+                                      }
                                       target="_blank"
                                       rel="noreferrer noopener"
                                       className="attachment-media-link"
                                     >
                                       <img
-                                        src={attachmentUrl}
+                                        src={
+                                          resolveApiUrl(
+                                            m.widget?.thumbnailUrl || "",
+                                          ) || attachmentUrl
+                                        }
                                         alt={
                                           m.widget?.filename ||
                                           m.widget?.title ||
@@ -804,6 +1232,9 @@ export default function App() {
                                           m.widget?.description}
                                       </p>
                                     )}
+                                    {m.widget?.blocked && (
+                                      <p>{m.widget?.blockedReason}</p>
+                                    )}
                                     {attachmentUrl && (
                                       <a
                                         href={attachmentUrl}
@@ -881,6 +1312,221 @@ export default function App() {
                                   )}
                                 </div>
                               )}
+                            {m.sender === "agent" &&
+                              m.widget?.type === "callback" &&
+                              Array.isArray(m.widget?.slots) && (
+                                <div className="message-widget inline-form-widget">
+                                  {submittedWidgets[m.id] ? (
+                                    <span className="inline-submitted-value">
+                                      {submittedWidgets[m.id]}
+                                    </span>
+                                  ) : (
+                                    <>
+                                      <div className="callback-slots">
+                                        {m.widget.slots.map((slot) => (
+                                          <button
+                                            key={`${m.id}-${slot.startsAt}`}
+                                            type="button"
+                                            className={`callback-slot ${
+                                              formInputs[formKey(m.id)]
+                                                ?.startsAt === slot.startsAt
+                                                ? "callback-slot-active"
+                                                : ""
+                                            }`}
+                                            onClick={() =>
+                                              setFormInputs((prev) => ({
+                                                ...prev,
+                                                [formKey(m.id)]: {
+                                                  ...(prev[formKey(m.id)] ||
+                                                    {}),
+                                                  startsAt: slot.startsAt,
+                                                  error: "",
+                                                },
+                                              }))
+                                            }
+                                          >
+                                            {slot.label}
+                                          </button>
+                                        ))}
+                                      </div>
+                                      {m.widget?.timezone && (
+                                        <span className="callback-timezone">
+                                          Times in {m.widget.timezone}
+                                        </span>
+                                      )}
+                                      {m.widget?.askPhone !== false && (
+                                        <input
+                                          className="inline-input"
+                                          type="tel"
+                                          placeholder="Phone number"
+                                          value={
+                                            formInputs[formKey(m.id)]?.phone ||
+                                            ""
+                                          }
+                                          onChange={(e) =>
+                                            setFormInputs((prev) => ({
+                                              ...prev,
+                                              [formKey(m.id)]: {
+                                                ...(prev[formKey(m.id)] || {}),
+                                                phone: e.target.value,
+                                              },
+                                            }))
+                                          }
+                                        />
+                                      )}
+                                      {formInputs[formKey(m.id)]?.error && (
+                                        <span className="callback-error">
+                                          {formInputs[formKey(m.id)].error}
+                                        </span>
+                                      )}
+                                      <button
+                                        type="button"
+                                        className="inline-submit"
+                                        disabled={
+                                          !formInputs[formKey(m.id)]?.startsAt
+                                        }
+                                        onClick={() => bookCallback(m)}
+                                      >
+                                        {m.widget?.submitLabel ||
+                                          "Book callback"}
+                                      </button>
+                                    </>
+                                  )}
+                                </div>
+                              )}
+                            {m.sender === "agent" &&
+                              m.widget?.type === "book_meeting" &&
+                              Array.isArray(m.widget?.slots) && (
+                                <div className="message-widget inline-form-widget">
+                                  {submittedWidgets[m.id] ? (
+                                    <span className="inline-submitted-value">
+                                      {submittedWidgets[m.id]}
+                                    </span>
+                                  ) : (
+                                    <>
+                                      <div className="callback-slots">
+                                        {m.widget.slots.map((slot) => (
+                                          <button
+                                            key={`${m.id}-${slot.startsAt}`}
+                                            type="button"
+                                            className={`callback-slot ${
+                                              formInputs[formKey(m.id)]
+                                                ?.startsAt === slot.startsAt
+                                                ? "callback-slot-active"
+                                                : ""
+                                            }`}
+                                            onClick={() =>
+                                              setFormInputs((prev) => ({
+                                                ...prev,
+                                                [formKey(m.id)]: {
+                                                  ...(prev[formKey(m.id)] ||
+                                                    {}),
+                                                  startsAt: slot.startsAt,
+                                                  error: "",
+                                                },
+                                              }))
+                                            }
+                                          >
+                                            {slot.label}
+                                          </button>
+                                        ))}
+                                      </div>
+                                      {m.widget?.timezone && (
+                                        <span className="callback-timezone">
+                                          Times in {m.widget.timezone}
+                                        </span>
+                                      )}
+                                      {m.widget?.askName !== false && (
+                                        <input
+                                          className="inline-input"
+                                          type="text"
+                                          placeholder="Your name"
+                                          value={
+                                            formInputs[formKey(m.id)]?.name ||
+                                            ""
+                                          }
+                                          onChange={(e) =>
+                                            setFormInputs((prev) => ({
+                                              ...prev,
+                                              [formKey(m.id)]: {
+                                                ...(prev[formKey(m.id)] || {}),
+                                                name: e.target.value,
+                                              },
+                                            }))
+                                          }
+                                        />
+                                      )}
+                                      {m.widget?.askEmail !== false && (
+                                        <input
+                                          className="inline-input"
+                                          type="email"
+                                          placeholder="Email for the invitation"
+                                          value={
+                                            formInputs[formKey(m.id)]?.email ||
+                                            ""
+                                          }
+                                          onChange={(e) =>
+                                            setFormInputs((prev) => ({
+                                              ...prev,
+                                              [formKey(m.id)]: {
+                                                ...(prev[formKey(m.id)] || {}),
+                                                email: e.target.value,
+                                              },
+                                            }))
+                                          }
+                                        />
+                                      )}
+                                      {formInputs[formKey(m.id)]?.error && (
+                                        <span className="callback-error">
+                                          {formInputs[formKey(m.id)].error}
+                                        </span>
+                                      )}
+                                      <button
+                                        type="button"
+                                        className="inline-submit"
+                                        disabled={
+                                          !formInputs[formKey(m.id)]?.startsAt
+                                        }
+                                        onClick={() => bookMeeting(m)}
+                                      >
+                                        {m.widget?.submitLabel ||
+                                          "Book meeting"}
+                                      </button>
+                                    </>
+                                  )}
+                                </div>
+                              )}
+                            {m.sender === "agent" &&
+                              m.widget?.type === "meeting" && (
+                                <div className="message-widget meeting-card">
+                                  <strong>{m.widget.title || "Meeting"}</strong>
+                                  <span>{m.widget.label}</span>
+                                  <span className="callback-timezone">
+                                    {m.widget.agentName
+                                      ? `With ${m.widget.agentName} · `
+                                      : ""}
+                                    {m.widget.timezone}
+                                  </span>
+                                  {m.widget.manageUrl && (
+                                    <div className="meeting-card-actions">
+                                      <a
+                                        href={m.widget.manageUrl}
+                                        target="_blank"
+                                        rel="noreferrer"
+                                      >
+                                        Reschedule
+                                      </a>
+                                      <a
+                                        href={m.widget.manageUrl}
+                                        target="_blank"
+                                        rel="noreferrer"
+                                      >
+                                        Cancel
+                                      </a>
+                                    </div>
+                                  )}
+                                </div>
+                              )}
                             {m.sender === "agent" &&
                               m.widget?.type === "input_form" &&
                               Array.isArray(m.widget?.fields) && (
@@ -1185,10 +1831,10 @@ export default function App() {
                                               `${API_URL}/api/session/${sessionId}/csat`,
                                               {
                                                 method: "POST",
-                                                headers: {
+                                                headers: sessionHeaders({
                                                   "Content-Type":
                                                     "application/json",
-                                                },
+                                                }),
                                                 body: JSON.stringify({
                                                   score: star,
                                                 }),
@@ -1218,10 +1864,10 @@ export default function App() {
                                                 `${API_URL}/api/session/${sessionId}/csat`,
                                                 {
                                                   method: "POST",
-                                                  headers: {
+                                                  headers: sessionHeaders({
                                                     "Content-Type":
                                                       "application/json",
-                                                  },
+                                                  }),
                                                   body: JSON.stringify({
                                                     score,
                                                   }),
@@ -1245,8 +1891,14 @@ export default function App() {
                 })}
                 {agentTyping && (
                   <div className="row row-agent row-typing row-open-animate">
-                    {typingAgent ? (
-                      renderAgentAvatar(typingAgent, 28)
+                    {typingAgents.length > 0 ? (
+                      <span className="typing-avatars">
+                        {typingAgents.slice(0, 3).map((typer, idx) => (
+                          <span key={`${typer.agentName}-${idx}`}>
+                            {renderAgentAvatar(typer, 28)}
+                          </span>
+                        ))}
+                      </span>
                     ) : brandSettings?.botAvatarUrl ? (
                       <img
                         className="agent-avatar"
@@ -1268,7 +1920,7 @@ export default function App() {
                     )}
                     <div
                       className="bubble bubble-agent typing-bubble"
-                      aria-label="Agent is typing"
+                      aria-label={typingLabel(typingAgents)}
                     >
                       <span className="typing-dot" />
                       <span className="typing-dot" />
@@ -1298,6 +1950,39 @@ export default function App() {
               </div>
             </main>
 
+            {aiConsent?.required && !aiConsent?.status && !chatClosed && (
+              <div className="ai-consent">
+                <p className="ai-consent-text">
+                  {aiConsent.text ||
+                    "Can our AI assistant help answer your messages? You can still reach a person either way."}
+                </p>
+                <div className="ai-consent-actions">
+                  <button
+                    type="button"
+                    className="ai-consent-btn ai-consent-allow"
+                    onClick={() =>
+                      answerAiConsent(true).catch((error) =>
+                        console.error("failed to save consent", error),
+                      )
+                    }
+                  >
+                    Allow
+                  </button>
+                  <button
+                    type="button"
+                    className="ai-consent-btn"
+                    onClick={() =>
+                      answerAiConsent(false).catch((error) =>
+                        console.error("failed to save consent", error),
+                      )
+                    }
+                  >
+                    No thanks
+                  </button>
+                </div>
+              </div>
+            )}
+
             {chatClosed ? (
               <div className="composer composer-closed">
                 <button